@@ -0,0 +1,190 @@
+/// Generates the solution registry that is compiled into the main binary when the `registry` feature is enabled.
+/// Every `src/bin/NN.rs` is included as a module and its `SOLUTION` is added to `SOLUTIONS`.
+/// It also records the compiler version, which is stored with benchmark history,
+/// and turns the example manifests in `data/examples/NN.json` and `data/YYYY/examples/NN.json` into tests.
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+#[allow(dead_code)]
+#[path = "src/template/examples.rs"]
+mod examples;
+
+use examples::{ExampleManifest, Expected};
+
+fn main() {
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".into());
+    if let Ok(output) = Command::new(rustc).arg("-V").output() {
+        let version = String::from_utf8_lossy(&output.stdout);
+        println!("cargo:rustc-env=AOC_RUSTC_VERSION={}", version.trim());
+    }
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("registry.rs");
+
+    let is_enabled = env::var_os("CARGO_FEATURE_REGISTRY").is_some();
+
+    // re-running the build script rebuilds every bin, so only watch the solutions when they are registered.
+    if is_enabled {
+        println!("cargo:rerun-if-changed=src/bin");
+    } else {
+        println!("cargo:rerun-if-changed=build.rs");
+    }
+
+    // every solution module declares a global allocator when profiling with dhat or counting allocations.
+    let is_dhat = env::var_os("CARGO_FEATURE_DHAT_HEAP").is_some();
+    let is_alloc_count = env::var_os("CARGO_FEATURE_ALLOC_COUNT").is_some();
+    if is_enabled && is_dhat {
+        println!(
+            "cargo:warning=the `registry` feature is disabled when profiling with `dhat-heap`."
+        );
+    } else if is_enabled && is_alloc_count {
+        println!(
+            "cargo:warning=the `registry` feature is disabled when counting allocations with `alloc-count`."
+        );
+    }
+
+    let mut days: Vec<String> = vec![];
+
+    if is_enabled && !is_dhat && !is_alloc_count {
+        let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+        for entry in fs::read_dir(bin_dir).unwrap() {
+            let path = entry.unwrap().path();
+            let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+
+            let is_day = stem.len() == 2 && stem.chars().all(|c| c.is_ascii_digit());
+            if is_day && path.extension().is_some_and(|e| e == "rs") {
+                days.push(stem.to_string());
+            }
+        }
+    }
+
+    days.sort();
+
+    let mut registry = String::new();
+
+    for day in &days {
+        let path = Path::new(&manifest_dir)
+            .join("src")
+            .join("bin")
+            .join(format!("{day}.rs"));
+
+        registry.push_str(&format!(
+            "#[cfg(not(test))]\n#[allow(dead_code)]\n#[path = {:?}]\nmod day_{day};\n\n",
+            path.display().to_string()
+        ));
+    }
+
+    registry.push_str(&format!(
+        "#[cfg(not(test))]\npub static SOLUTIONS: &[advent_of_code::template::runner::Solution] = &[{}];\n\n",
+        days.iter()
+            .map(|day| format!("day_{day}::SOLUTION"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    ));
+
+    registry.push_str(
+        "#[cfg(test)]\npub static SOLUTIONS: &[advent_of_code::template::runner::Solution] = &[];\n",
+    );
+
+    fs::write(out_path, registry).unwrap();
+
+    let data_dir = Path::new(&manifest_dir).join("data");
+    let out_dir = Path::new(&env::var("OUT_DIR").unwrap()).join("examples");
+
+    generate_example_tests(&data_dir.join("examples"), &out_dir);
+
+    // years other than `AOC_YEAR` keep their data in `data/YYYY/` and their solutions in `src/bin/YYYY/`.
+    // their bins are registered in `Cargo.toml`, so a new year is picked up when it changes.
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    for year in find_years(&[
+        data_dir.clone(),
+        Path::new(&manifest_dir).join("src").join("bin"),
+    ]) {
+        generate_example_tests(&data_dir.join(&year).join("examples"), &out_dir.join(&year));
+    }
+}
+
+/// Names of the year directories, e.g. `2023`, in any of the given directories.
+fn find_years(dirs: &[PathBuf]) -> Vec<String> {
+    let mut years: Vec<String> = dirs
+        .iter()
+        .filter_map(|dir| fs::read_dir(dir).ok())
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_name()?.to_str()?;
+            let is_year = name.len() == 4 && name.chars().all(|c| c.is_ascii_digit());
+            (is_year && path.is_dir()).then(|| name.to_string())
+        })
+        .collect();
+
+    years.sort();
+    years.dedup();
+    years
+}
+
+/// Generate a test per example and part into `N.rs` in `out_dir`, where it is included by `solution!`.
+/// Days without a manifest get an empty file, days with a malformed manifest a `compile_error!`.
+/// Only existing manifests are watched: watching the directory would rerun on every edit of an example, and a missing file on every build.
+/// `cargo examples` touches this script when it creates a manifest instead.
+fn generate_example_tests(examples_dir: &Path, out_dir: &Path) {
+    fs::create_dir_all(out_dir).unwrap();
+
+    for day in 1..=25 {
+        let manifest_path = examples_dir.join(format!("{day:02}.json"));
+        if manifest_path.exists() {
+            println!("cargo:rerun-if-changed={}", manifest_path.display());
+        }
+
+        // a broken manifest fails the tests of its day only, instead of every build.
+        let manifest = match ExampleManifest::read_from_file(&manifest_path) {
+            Ok(manifest) => manifest.unwrap_or_default(),
+            Err(e) => {
+                let message = format!("could not parse {}: {e}", manifest_path.display());
+                fs::write(
+                    out_dir.join(format!("{day}.rs")),
+                    format!("compile_error!({message:?});\n"),
+                )
+                .unwrap();
+                continue;
+            }
+        };
+
+        let mut tests = String::new();
+
+        for example in &manifest.examples {
+            let name: String = example
+                .file
+                .trim_end_matches(".txt")
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                .collect();
+
+            let input_path = examples_dir.join(&example.file);
+
+            for (part, func) in [(1, "part_one"), (2, "part_two")] {
+                let Some(expected) = example.expected(part) else {
+                    continue;
+                };
+
+                let expected = match expected {
+                    Expected::Answer(answer) => format!("Some(String::from({answer:?}))"),
+                    Expected::Unsolved => "None".into(),
+                };
+
+                tests.push_str(&format!(
+                    "#[test]\nfn example_{name}_{func}() {{\n    let input = include_str!({:?});\n    assert_eq!({func}(input).map(|result| result.to_string()), {expected});\n}}\n\n",
+                    input_path.display().to_string()
+                ));
+            }
+        }
+
+        fs::write(out_dir.join(format!("{day}.rs")), tests).unwrap();
+    }
+}
@@ -37,7 +37,7 @@ fn is_safe(report: &[u32]) -> bool {
     for i in 1..report.len() {
         let a = report[i - 1];
         let b = report[i];
-        let diff = if a > b { a - b } else { b - a };
+        let diff = a.abs_diff(b);
         if !(1..=3).contains(&diff) {
             return false;
         }
@@ -30,7 +30,7 @@ pub fn part_two(input: &str) -> Option<u32> {
                 0
             } else if dont_stmt_opt.is_some() {
                 mul_enabled = false;
-                return 0;
+                0
             } else {
                 let a_opt = caps.name("a");
                 let b_opt = caps.name("b");
@@ -39,9 +39,9 @@ pub fn part_two(input: &str) -> Option<u32> {
                         let a = a_match.as_str().parse::<u32>().unwrap();
                         let b = b_match.as_str().parse::<u32>().unwrap();
                         if mul_enabled {
-                            return a * b;
+                            a * b
                         } else {
-                            return 0;
+                            0
                         }
                     }
                     _ => panic!("Whoops!"),
@@ -11,21 +11,21 @@ fn produce_table(input: &str) -> Vec<String> {
     result
 }
 
-fn dims(table: &Vec<String>) -> (usize, usize) {
+fn dims(table: &[String]) -> (usize, usize) {
     (table[0].len(), table.len())
 }
 
-fn horizontal_strings(table: &Vec<String>) -> Vec<String> {
-    table.clone()
+fn horizontal_strings(table: &[String]) -> Vec<String> {
+    table.to_vec()
 }
 
-fn vertical_strings(table: &Vec<String>) -> Vec<String> {
-    let (width, height) = dims(&table);
+fn vertical_strings(table: &[String]) -> Vec<String> {
+    let (width, _) = dims(table);
     let mut result = Vec::new();
     for j in 0..width {
         let mut vert_str = String::new();
-        for i in 0..height {
-            let ch = table[i].as_bytes()[j] as char;
+        for row in table {
+            let ch = row.as_bytes()[j] as char;
             vert_str.push(ch);
         }
         result.push(vert_str);
@@ -33,8 +33,8 @@ fn vertical_strings(table: &Vec<String>) -> Vec<String> {
     result
 }
 
-fn diagonal_strings(table: &Vec<String>) -> Vec<String> {
-    let (width, height) = dims(&table);
+fn diagonal_strings(table: &[String]) -> Vec<String> {
+    let (width, height) = dims(table);
     let mut result = Vec::new();
 
     // top left to bottom right
@@ -63,7 +63,7 @@ fn diagonal_strings(table: &Vec<String>) -> Vec<String> {
     // top right to left bottom
     for x_offset in 0..width {
         let mut diag_str = String::new();
-        let js = ((0 + x_offset)..width).rev();
+        let js = (x_offset..width).rev();
         let is = 0..height;
         for (j, i) in js.zip(is) {
             let ch = table[i].as_bytes()[j - x_offset] as char;
@@ -75,7 +75,7 @@ fn diagonal_strings(table: &Vec<String>) -> Vec<String> {
     for y_offset in 1..height {
         let mut diag_str = String::new();
         let js = (0..width).rev();
-        let is = (0 + y_offset)..height;
+        let is = y_offset..height;
         for (j, i) in js.zip(is) {
             let ch = table[i].as_bytes()[j] as char;
             diag_str.push(ch);
@@ -86,8 +86,8 @@ fn diagonal_strings(table: &Vec<String>) -> Vec<String> {
     result
 }
 
-fn num_of_xmas(strings: &Vec<String>) -> usize {
-    fn xmas_in_str(string: &String) -> usize {
+fn num_of_xmas(strings: &[String]) -> usize {
+    fn xmas_in_str(string: &str) -> usize {
         let mut result = 0;
         if string.len() >= 4 {
             for i in 0..string.len() {
@@ -100,7 +100,7 @@ fn num_of_xmas(strings: &Vec<String>) -> usize {
         }
         result
     }
-    strings.iter().map(xmas_in_str).sum()
+    strings.iter().map(|s| xmas_in_str(s)).sum()
 }
 
 pub fn part_one(input: &str) -> Option<usize> {
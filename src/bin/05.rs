@@ -59,8 +59,8 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(result)
 }
 
-fn fix_update(rules: &BTreeMap<u8, Vec<u8>>, update: &Vec<u8>) -> Vec<u8> {
-    let mut result: Vec<u8> = update.clone();
+fn fix_update(rules: &BTreeMap<u8, Vec<u8>>, update: &[u8]) -> Vec<u8> {
+    let mut result: Vec<u8> = update.to_vec();
 
     result.sort_by(|x, y| match rules.get(x) {
         None => Ordering::Equal,
@@ -87,7 +87,7 @@ pub fn part_two(input: &str) -> Option<u32> {
                     Some(excludes) => {
                         for excluded_val in excludes {
                             if update[i + 1..].contains(excluded_val) {
-                                let fixed = fix_update(&rules, &update);
+                                let fixed = fix_update(&rules, update);
                                 return Some(fixed[fixed.len() / 2] as u32);
                             }
                         }
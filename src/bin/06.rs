@@ -30,13 +30,13 @@ struct Guard {
 
 impl Guard {
     fn new(direction: Direction, x_pos: i32, y_pos: i32) -> Guard {
-        return Guard {
+        Guard {
             direction,
             x_pos,
             y_pos,
             last_x_pos: x_pos,
             last_y_pos: y_pos,
-        };
+        }
     }
 
     fn walk_one_step(&mut self) {
@@ -117,7 +117,7 @@ impl WalkResult {
 }
 
 fn simulate_walking_path(puzzle_map: &HashMap<(i32, i32), FieldType>, guard: &Guard) -> WalkResult {
-    let mut guard = guard.clone();
+    let mut guard = *guard;
     let mut seen_map = HashMap::new();
 
     seen_map.insert((guard.x_pos, guard.y_pos), vec![guard.direction]);
@@ -125,26 +125,21 @@ fn simulate_walking_path(puzzle_map: &HashMap<(i32, i32), FieldType>, guard: &Gu
     loop {
         guard.walk_one_step();
 
-        match seen_map.get(&(guard.x_pos, guard.y_pos)) {
-            None => {}
-            Some(directions) => {
-                if directions.contains(&guard.direction) {
-                    // Found a cycle!
-                    return WalkResult::Cycle(seen_map);
-                }
+        if let Some(directions) = seen_map.get(&(guard.x_pos, guard.y_pos)) {
+            if directions.contains(&guard.direction) {
+                // Found a cycle!
+                return WalkResult::Cycle(seen_map);
             }
         }
 
         match puzzle_map.get(&(guard.x_pos, guard.y_pos)) {
             None => break, // Outside of map!
-            Some(field) => match &field {
-                FieldType::Obstacle => {
-                    guard.reset_step();
-                    guard.turn_90_degrees();
-                    continue;
-                }
-                _ => {}
-            },
+            Some(FieldType::Obstacle) => {
+                guard.reset_step();
+                guard.turn_90_degrees();
+                continue;
+            }
+            Some(_) => {}
         }
 
         seen_map
@@ -175,7 +170,7 @@ fn count_cycle_causing_obstacles(
             new_puzzle_map
                 .entry((*x, *y))
                 .and_modify(|v| *v = FieldType::Obstacle);
-            simulate_walking_path(&new_puzzle_map, &guard)
+            simulate_walking_path(&new_puzzle_map, guard)
         })
         .filter(|walk_result| match *walk_result {
             WalkResult::Cycle(_) => true,
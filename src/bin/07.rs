@@ -9,8 +9,8 @@ struct MultiSetGenerator<E> {
 }
 
 impl<E: Ord + Clone> MultiSetGenerator<E> {
-    fn new_of_size(elems: &Vec<E>, n: usize) -> MultiSetGenerator<E> {
-        let mut elems_copy = elems.clone();
+    fn new_of_size(elems: &[E], n: usize) -> MultiSetGenerator<E> {
+        let mut elems_copy = elems.to_vec();
         elems_copy.dedup();
         elems_copy.sort();
 
@@ -94,7 +94,7 @@ enum Operation {
     Con,
 }
 
-fn validate_equation(equation: &Equation, operations: &Vec<Operation>) -> bool {
+fn validate_equation(equation: &Equation, operations: &[Operation]) -> bool {
     if let Some((first, rest)) = equation.factors.split_first() {
         for operations in MultiSetGenerator::new_of_size(operations, equation.factors.len() - 1) {
             let calc_result: u64 =
@@ -120,7 +120,7 @@ fn validate_equation(equation: &Equation, operations: &Vec<Operation>) -> bool {
     false
 }
 
-fn solve_with(operations: &Vec<Operation>, input: &str) -> Option<u64> {
+fn solve_with(operations: &[Operation], input: &str) -> Option<u64> {
     let equations = parse_equations(input);
     Some(
         equations
@@ -138,12 +138,12 @@ fn solve_with(operations: &Vec<Operation>, input: &str) -> Option<u64> {
 
 pub fn part_one(input: &str) -> Option<u64> {
     use Operation::*;
-    solve_with(&vec![Add, Mul], input)
+    solve_with(&[Add, Mul], input)
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
     use Operation::*;
-    solve_with(&vec![Add, Mul, Con], input)
+    solve_with(&[Add, Mul, Con], input)
 }
 
 #[cfg(test)]
@@ -153,8 +153,7 @@ mod tests {
     #[test]
     fn foo() {
         use Operation::*;
-        let result: Vec<Vec<Operation>> =
-            MultiSetGenerator::new_of_size(&vec![Add, Mul], 2).collect();
+        let result: Vec<Vec<Operation>> = MultiSetGenerator::new_of_size(&[Add, Mul], 2).collect();
         let expected: Vec<Vec<Operation>> = vec![
             vec![Add, Add],
             vec![Mul, Add],
@@ -29,8 +29,8 @@ impl Position {
 
     fn get_extended_antinodes_for(&self, other: Self, min: &Position, max: &Position) -> Vec<Self> {
         let mut result = Vec::new();
-        let mut curr = self.clone();
-        let mut other = other.clone();
+        let mut curr = *self;
+        let mut other = other;
 
         loop {
             let new = curr.get_antinode_for(other);
@@ -100,7 +100,7 @@ fn calc_antinodes(
     extended: bool,
 ) -> HashSet<Position> {
     fn calc_for_freq(
-        antennas_same_freq: &Vec<Antenna>,
+        antennas_same_freq: &[Antenna],
         max_pos: &Position,
         extended: bool,
     ) -> HashSet<Position> {
@@ -140,17 +140,16 @@ fn calc_antinodes(
     }
 
     grouped_antennas
-        .iter()
-        .map(|(_, v)| calc_for_freq(v, max_pos, extended))
-        .flatten()
+        .values()
+        .flat_map(|v| calc_for_freq(v, max_pos, extended))
         .collect()
 }
 
 pub fn solve_with(extended: bool, input: &str) -> Option<usize> {
     let fields = get_fields(input);
     let antennas = fields
-        .iter()
-        .filter_map(|(_, v)| match v {
+        .values()
+        .filter_map(|v| match v {
             Field::EmptyField => None,
             Field::AntennaField(antenna) => Some(*antenna),
         })
@@ -169,10 +168,7 @@ pub fn solve_with(extended: bool, input: &str) -> Option<usize> {
     Some(
         antinodes
             .iter()
-            .filter(|pos| match fields.get(pos) {
-                Some(_) => true,
-                _ => false,
-            })
+            .filter(|pos| fields.contains_key(pos))
             .count(),
     )
 }
@@ -18,17 +18,11 @@ impl Chunk {
     }
 
     fn is_file(&self) -> bool {
-        match self {
-            Chunk::File(_, _) => true,
-            _ => false,
-        }
+        matches!(self, Chunk::File(_, _))
     }
 
     fn is_empty(&self) -> bool {
-        match self {
-            Chunk::Empty(_) => true,
-            _ => false,
-        }
+        matches!(self, Chunk::Empty(_))
     }
 }
 
@@ -143,7 +137,7 @@ fn compact_chunks_unfragmented(memory: &mut Vec<Chunk>) {
     }
 }
 
-fn compute_checksum(memory: &Vec<Chunk>) -> u64 {
+fn compute_checksum(memory: &[Chunk]) -> u64 {
     let mut index: u64 = 0;
     memory
         .iter()
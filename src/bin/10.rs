@@ -21,7 +21,7 @@ impl Position {
     }
 
     fn moved(&self, x: i32, y: i32) -> Position {
-        let mut clone = self.clone();
+        let mut clone = *self;
         clone.move_x(x);
         clone.move_y(y);
         clone
@@ -45,7 +45,7 @@ impl HeightMap {
     }
 
     fn get_height(&self, pos: &Position) -> Option<Height> {
-        self.0.get(pos).map(|height| *height)
+        self.0.get(pos).copied()
     }
 
     fn start_positions(&self) -> Vec<Position> {
@@ -26,7 +26,7 @@ fn blink(stone: u64) -> BlinkResult {
         0 => This(1),
         n => {
             let num_digits = 1 + (f64::floor(f64::log10(n as f64)) as u32);
-            if num_digits % 2 == 0 {
+            if num_digits.is_multiple_of(2) {
                 let factor = u64::pow(10, num_digits / 2);
                 let first = n / factor;
                 let second = n % factor;
@@ -40,7 +40,7 @@ struct Machine {
 fn parse_input(input: &str) -> Vec<Machine> {
     let re = Regex::new(r"(Button A: X\+(?<ax>\d+), Y\+(?<ay>\d+)\nButton B: X\+(?<bx>\d+), Y\+(?<by>\d+)\nPrize: X=(?<px>\d+), Y=(?<py>\d+))").unwrap();
     re.captures_iter(input)
-        .map(|caps| {
+        .filter_map(|caps| {
             let ax = caps.name("ax")?.as_str().parse::<u32>().ok()?;
             let ay = caps.name("ay")?.as_str().parse::<u32>().ok()?;
             let bx = caps.name("bx")?.as_str().parse::<u32>().ok()?;
@@ -53,7 +53,6 @@ fn parse_input(input: &str) -> Vec<Machine> {
                 prize: Position { x: px, y: py },
             })
         })
-        .flatten()
         .collect()
 }
 
@@ -102,7 +101,7 @@ fn prize_cost(machine: &Machine) -> Option<u32> {
             let times_b = i;
             let final_pos = machine.vec_a.scale(times_a) + machine.vec_b.scale(times_b);
             if final_pos == machine.prize {
-                return Some(3 * times_a + 1 * times_b);
+                return Some(3 * times_a + times_b);
             } else if final_pos.x < machine.prize.x || final_pos.y < machine.prize.y {
                 all_greater = false
             }
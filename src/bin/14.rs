@@ -62,7 +62,7 @@ impl Arena {
                     print!(" ")
                 }
             }
-            print!("\n");
+            println!();
         }
     }
 }
@@ -70,7 +70,7 @@ impl Arena {
 fn parse_input(input: &str) -> Vec<Robot> {
     let re = Regex::new(r"(p=(?<px>\d+),(?<py>\d+) v=(?<vx>-?\d+),(?<vy>-?\d+))").unwrap();
     re.captures_iter(input)
-        .map(|caps| {
+        .filter_map(|caps| {
             let px = caps.name("px")?.as_str().parse::<i32>().ok()?;
             let py = caps.name("py")?.as_str().parse::<i32>().ok()?;
             let vx = caps.name("vx")?.as_str().parse::<i32>().ok()?;
@@ -80,7 +80,6 @@ fn parse_input(input: &str) -> Vec<Robot> {
                 vel: Vec2 { x: vx, y: vy },
             })
         })
-        .flatten()
         .collect()
 }
 
@@ -99,7 +99,7 @@ impl Warehouse {
                     Some(MovableObject::Box) => print!("O"),
                 }
             }
-            print!("\n");
+            println!();
         }
     }
 }
@@ -291,7 +291,7 @@ impl WideWarehouse {
                     Some(ObjectPart::BoxRight) => print!("]"),
                 }
             }
-            print!("\n");
+            println!();
         }
     }
 }
@@ -314,11 +314,9 @@ fn parse_input(input: &str) -> (Warehouse, Vec<Direction>) {
                 '@' => robot_pos = Some(pos),
                 '#' => {
                     warehouse_objs.insert(pos, MovableObject::Wall);
-                    ()
                 }
                 'O' => {
                     warehouse_objs.insert(pos, MovableObject::Box);
-                    ()
                 }
                 _ => (),
             }
@@ -1,6 +1,5 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::hash::Hash;
-use std::u32;
 
 advent_of_code::solution!(16);
 
@@ -77,7 +76,6 @@ fn parse_input(input: &str) -> Maze {
                 }
                 '.' => {
                     paths.insert(pos);
-                    ()
                 }
                 _ => (),
             }
@@ -167,10 +165,7 @@ fn flood_maze(maze: Maze) -> HashMap<Vec2, Field> {
         let cost = visits.get(&pos).unwrap().cost(Direction::Horizontal);
         for nbr in pos.neighbors(Direction::Horizontal) {
             visits.entry(nbr).and_modify(|nbr| {
-                let updated = nbr.update(
-                    cost.checked_add(1).unwrap_or(u32::MAX),
-                    Direction::Horizontal,
-                );
+                let updated = nbr.update(cost.saturating_add(1), Direction::Horizontal);
                 if updated {
                     frontier.push_back(nbr.position);
                 }
@@ -180,8 +175,7 @@ fn flood_maze(maze: Maze) -> HashMap<Vec2, Field> {
         let cost = visits.get(&pos).unwrap().cost(Direction::Vertical);
         for nbr in pos.neighbors(Direction::Vertical) {
             visits.entry(nbr).and_modify(|nbr| {
-                let updated =
-                    nbr.update(cost.checked_add(1).unwrap_or(u32::MAX), Direction::Vertical);
+                let updated = nbr.update(cost.saturating_add(1), Direction::Vertical);
                 if updated {
                     frontier.push_back(nbr.position);
                 }
@@ -194,7 +188,7 @@ fn flood_maze(maze: Maze) -> HashMap<Vec2, Field> {
 
 pub fn part_one(input: &str) -> Option<u32> {
     let maze = parse_input(input);
-    let end_pos = maze.end.clone();
+    let end_pos = maze.end;
     let visits = flood_maze(maze);
     visits.get(&end_pos).map(|field| {
         u32::min(
@@ -205,7 +199,7 @@ pub fn part_one(input: &str) -> Option<u32> {
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    let maze = parse_input(input);
+    let _maze = parse_input(input);
     None
 }
 
@@ -226,36 +220,4 @@ mod tests {
         assert_eq!(field.cost(Direction::Horizontal), 100);
         assert_eq!(field.cost(Direction::Vertical), 110);
     }
-
-    #[test]
-    fn test_examples() {
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
-        ));
-        assert_eq!(result, Some(1004));
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
-        assert_eq!(result, Some(3008));
-    }
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 3,
-        ));
-        assert_eq!(result, Some(7036));
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 4,
-        ));
-        assert_eq!(result, Some(11048));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
-        ));
-        assert_eq!(result, None);
-    }
 }
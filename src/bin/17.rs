@@ -1,4 +1,4 @@
-use itertools::{intersperse, Itertools};
+use itertools::Itertools;
 use regex::Regex;
 use std::ops::BitXor;
 
@@ -80,7 +80,7 @@ impl Iterator for VirtualMachine {
             (0, n) => {
                 let v = combo_op(*n);
                 let divisor = i32::pow(2, if v < 0 { 0 } else { v as u32 });
-                self.reg_a = self.reg_a / divisor;
+                self.reg_a /= divisor;
             }
             (1, n) => {
                 self.reg_b = self.reg_b.bitxor(*n as i32);
@@ -123,10 +123,12 @@ impl Iterator for VirtualMachine {
 }
 
 fn run_vm(vm: VirtualMachine) -> String {
-    vm.filter_map(|x| x.map(|n| n.to_string()))
-        .intersperse(String::from(","))
-        .collect::<Vec<_>>()
-        .concat()
+    Itertools::intersperse(
+        vm.filter_map(|x| x.map(|n| n.to_string())),
+        String::from(","),
+    )
+    .collect::<Vec<_>>()
+    .concat()
 }
 
 pub fn part_one(input: &str) -> Option<String> {
@@ -34,9 +34,8 @@ impl Vec2 {
 
 fn build_graph_edges(size: i32) -> impl Iterator<Item = (Vec2, Vec2)> {
     (0..size)
-        .map(move |y| (0..size).map(|x| Vec2 { x, y }).collect::<Vec<_>>())
-        .flatten()
-        .map(move |pos| {
+        .flat_map(move |y| (0..size).map(|x| Vec2 { x, y }).collect::<Vec<_>>())
+        .flat_map(move |pos| {
             pos.neighbors()
                 .into_iter()
                 .filter(|nbrpos| {
@@ -45,7 +44,6 @@ fn build_graph_edges(size: i32) -> impl Iterator<Item = (Vec2, Vec2)> {
                 .map(|nbrpos| (pos, nbrpos))
                 .collect::<Vec<_>>()
         })
-        .flatten()
 }
 
 fn parse_input(input: &str) -> impl Iterator<Item = Vec2> + '_ {
@@ -75,7 +73,7 @@ fn part_one_sized(input: &str, size: i32, fallen_bytes: usize) -> Option<i32> {
 
     let result = dijkstra(&graph, start, Some(end), |_| 1);
     let result = result.get(&end);
-    result.map(|x| *x)
+    result.copied()
 }
 
 pub fn part_one(input: &str) -> Option<i32> {
@@ -38,7 +38,7 @@ enum Direction {
 }
 
 impl Direction {
-    fn to_direction_key(&self) -> DirectionKey {
+    fn to_direction_key(self) -> DirectionKey {
         match self {
             Direction::Up => DirectionKey::Up,
             Direction::Down => DirectionKey::Down,
@@ -120,7 +120,7 @@ trait Keypad {
     }
 
     fn shortest_path(presses: Vec<Self::Key>) -> Vec<Vec<Direction>> {
-        let keys = vec![Self::START_KEY].into_iter().chain(presses.into_iter());
+        let keys = vec![Self::START_KEY].into_iter().chain(presses);
         Associator::assocs(keys)
             .map(|(from, to)| Self::shortest_path_for_single_motion(from, to))
             .collect()
@@ -288,7 +288,7 @@ fn parse_input(input: &str) -> Vec<Vec<NumericKey>> {
 }
 
 pub fn part_one(input: &str) -> Option<usize> {
-    fn extract_numeric_part(code: &Vec<NumericKey>) -> usize {
+    fn extract_numeric_part(code: &[NumericKey]) -> usize {
         code.iter()
             .filter_map(|key| match key {
                 NumericKey::Zero => Some(0.to_string()),
@@ -15,7 +15,7 @@ struct AllPairs<I: Iterator> {
 }
 
 impl<I: Iterator> AllPairs<I> {
-    fn all_pairs(iter: I) -> AllPairs<I> {
+    fn new(iter: I) -> AllPairs<I> {
         AllPairs {
             elements: iter.collect(),
             curr: None,
@@ -94,7 +94,7 @@ pub fn part_one(input: &str) -> Option<usize> {
     for node in g.nodes() {
         if node.starts_with("t") {
             let nbrs = g.neighbors(node);
-            for (nbr1, nbr2) in AllPairs::all_pairs(nbrs) {
+            for (nbr1, nbr2) in AllPairs::new(nbrs) {
                 if g.neighbors(nbr1).collect::<HashSet<_>>().contains(nbr2) {
                     counted.insert(sorted_three_tuple(node, nbr1, nbr2));
                 }
@@ -116,8 +116,7 @@ impl<N: Eq + Ord + Hash + Clone> Cluster<N> {
 
     fn subclusters(&self) -> HashSet<Cluster<N>> {
         (2..=self.elems.len())
-            .map(|n| self.subclusters_of_size(n))
-            .flatten()
+            .flat_map(|n| self.subclusters_of_size(n))
             .collect()
     }
 
@@ -157,9 +156,7 @@ impl<N: Eq + Ord + Hash> PartialEq for Cluster<N> {
     }
 }
 
-impl<N: Eq + Ord + Hash> Eq for Cluster<N> {
-    fn assert_receiver_is_total_eq(&self) {}
-}
+impl<N: Eq + Ord + Hash> Eq for Cluster<N> {}
 
 fn get_clusters<N: PartialEq + Ord + Copy + Hash>(
     graph: &UnGraphMap<N, ()>,
@@ -176,7 +173,7 @@ where
     N: Debug + PartialEq + Eq + Ord + Hash + Clone + Copy,
 {
     let mut cluster_count_map: HashMap<Cluster<_>, usize> = HashMap::new();
-    for cluster in get_clusters(g).map(|c| c.subclusters()).flatten() {
+    for cluster in get_clusters(g).flat_map(|c| c.subclusters()) {
         cluster_count_map
             .entry(cluster)
             .and_modify(|count| *count += 1)
@@ -198,14 +195,12 @@ pub fn part_two(input: &str) -> Option<String> {
     let g = parse_input(input);
 
     let mut components = fully_connected_components(&g).collect::<Vec<_>>();
-    components.sort_by(|a, b| a.elems.len().cmp(&b.elems.len()));
+    components.sort_by_key(|a| a.elems.len());
 
     if let Some(cluster) = components.last() {
         let mut nodes = cluster.clone().elems.into_iter().collect::<Vec<_>>();
         nodes.sort();
-        let result = nodes
-            .into_iter()
-            .intersperse(",")
+        let result = Itertools::intersperse(nodes.into_iter(), ",")
             .collect::<Vec<_>>()
             .concat();
         return Some(result);
@@ -221,7 +216,7 @@ mod tests {
     #[test]
     fn test_all_pairs() {
         let input = vec![1, 2, 3];
-        let result = AllPairs::all_pairs(input.into_iter()).collect::<Vec<_>>();
+        let result = AllPairs::new(input.into_iter()).collect::<Vec<_>>();
         assert_eq!(result, vec![(1, 2), (1, 3), (2, 3)]);
     }
 
@@ -47,7 +47,7 @@ fn parse_input_for_part_one(input: &str) -> HashMap<String, Comp> {
             if caps.name("constant").is_some() {
                 let name = caps.name("cn").unwrap().as_str().to_string();
                 let value = caps.name("cv").unwrap().as_str();
-                let boolval = if value == "1" { true } else { false };
+                let boolval = value == "1";
                 (name, Comp::Value(boolval))
             } else {
                 let name1 = caps.name("a").unwrap().as_str().to_string();
@@ -73,14 +73,14 @@ fn parse_input_for_part_one(input: &str) -> HashMap<String, Comp> {
 
 pub fn part_one(input: &str) -> Option<u64> {
     let vals = parse_input_for_part_one(input);
-    let zkeys = vals.keys().cloned().filter(|n| n.starts_with("z"));
+    let zkeys = vals.keys().filter(|&n| n.starts_with("z")).cloned();
     let mut zvals = Vec::new();
     for key in zkeys {
         let thunk = vals.get(&key).unwrap();
         let val = thunk.eval(&vals);
         zvals.push((key, val));
     }
-    zvals.sort_by(|(name1, _), (name2, _)| name1.cmp(&name2));
+    zvals.sort_by(|(name1, _), (name2, _)| name1.cmp(name2));
     let result = zvals
         .into_iter()
         .enumerate()
@@ -1,18 +1,27 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, download, examples, harvest, read, scaffold, solve, time, verify,
+};
+use advent_of_code::template::runner::RunOptions;
 use args::{parse, AppArguments};
 
+/// Solutions compiled into this binary, see the `registry` feature.
+mod registry {
+    include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+}
+
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
+use advent_of_code::template::{Day, Year};
 #[cfg(feature = "today")]
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{runner::Limits, Day, Year};
+    use std::{process, time::Duration};
 
     pub enum AppArguments {
         Download {
             day: Day,
+            force: bool,
         },
         Read {
             day: Day,
@@ -21,43 +30,142 @@ mod args {
             day: Day,
             download: bool,
             overwrite: bool,
+            template: Option<String>,
+            dry_run: bool,
+        },
+        Examples {
+            day: Day,
+            blocks: Option<String>,
+            overwrite: bool,
+        },
+        Harvest {
+            overwrite: bool,
         },
         Solve {
             day: Day,
             release: bool,
             dhat: bool,
+            alloc: bool,
+            input: Option<String>,
+            all_inputs: bool,
+            example: Option<Option<u8>>,
             submit: Option<u8>,
         },
         All {
             release: bool,
+            jobs: Option<usize>,
+            limits: Limits,
+            alloc: bool,
+        },
+        Verify {
+            release: bool,
+            jobs: Option<usize>,
+            limits: Limits,
+            alloc: bool,
         },
         Time {
             all: bool,
             day: Option<Day>,
             store: bool,
+            accept: bool,
+            threshold: Option<f64>,
+            jobs: usize,
+            bench_time: Option<Duration>,
+            limits: Limits,
+            alloc: bool,
+        },
+        TimeHistory {
+            day: Day,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
+    /// Parse a non-negative, finite number of seconds, e.g. `2.5`.
+    fn parse_seconds(s: &str) -> Result<Duration, String> {
+        let seconds: f64 = s.parse().map_err(|e| format!("{e}"))?;
+        Duration::try_from_secs_f64(seconds).map_err(|e| format!("{e}"))
+    }
+
+    /// Parse `--timeout <secs>` (per part), `--day-timeout <secs>` and `--memory-limit <MiB>`.
+    /// Days can override them in `limits.json`, see the runner.
+    fn parse_limits(args: &mut pico_args::Arguments) -> Result<Limits, pico_args::Error> {
+        Ok(Limits {
+            part_timeout: args.opt_value_from_fn("--timeout", parse_seconds)?,
+            day_timeout: args.opt_value_from_fn("--day-timeout", parse_seconds)?,
+            memory: args.opt_value_from_fn("--memory-limit", |s| {
+                let mib = s.parse::<u64>().map_err(|e| format!("{e}"))?;
+                Limits::bytes_from_mib(mib).ok_or_else(|| format!("{mib} MiB is too large"))
+            })?,
+            ..Limits::default()
+        })
+    }
+
+    /// Parse `--example [N]`, the example number is optional.
+    fn parse_example(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<Option<u8>>, pico_args::Error> {
+        match args.opt_value_from_str::<_, u8>("--example") {
+            Ok(number) => Ok(number.map(Some)),
+            Err(
+                pico_args::Error::OptionWithoutAValue(_)
+                | pico_args::Error::Utf8ArgumentParsingFailed { .. },
+            ) => Ok(args.contains("--example").then_some(None)),
+            Err(e) => Err(e),
+        }
+    }
+
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let subcommand = args.subcommand()?;
+
+        // days are validated against the year, so select it before parsing them.
+        if let Some(year) = args.opt_value_from_str::<_, Year>("--year")? {
+            Year::select(year);
+        }
+
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
                 release: args.contains("--release"),
+                jobs: args.opt_value_from_str(["-j", "--jobs"])?,
+                limits: parse_limits(&mut args)?,
+                alloc: args.contains("--alloc"),
+            },
+            Some("verify") => AppArguments::Verify {
+                release: args.contains("--release"),
+                jobs: args.opt_value_from_str(["-j", "--jobs"])?,
+                limits: parse_limits(&mut args)?,
+                alloc: args.contains("--alloc"),
+            },
+            Some("time") if args.contains("--history") => AppArguments::TimeHistory {
+                day: args.free_from_str()?,
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let accept = args.contains("--accept");
+                let threshold = args.opt_value_from_str("--threshold")?;
+                // run sequentially by default so that benchmarks remain comparable.
+                let jobs = args.opt_value_from_str(["-j", "--jobs"])?.unwrap_or(1);
+                let bench_time = args.opt_value_from_fn("--bench-time", parse_seconds)?;
+                let limits = parse_limits(&mut args)?;
+                let alloc = args.contains("--alloc");
 
                 AppArguments::Time {
                     all,
                     day: args.opt_free_from_str()?,
                     store,
+                    accept,
+                    threshold,
+                    jobs,
+                    bench_time,
+                    limits,
+                    alloc,
                 }
             }
             Some("download") => AppArguments::Download {
+                force: args.contains("--force"),
                 day: args.free_from_str()?,
             },
             Some("read") => AppArguments::Read {
@@ -67,12 +175,26 @@ mod args {
                 day: args.free_from_str()?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                template: args.opt_value_from_str("--template")?,
+                dry_run: args.contains("--dry-run"),
+            },
+            Some("examples") => AppArguments::Examples {
+                day: args.free_from_str()?,
+                blocks: args.opt_value_from_str("--blocks")?,
+                overwrite: args.contains("--overwrite"),
+            },
+            Some("harvest") => AppArguments::Harvest {
+                overwrite: args.contains("--overwrite"),
             },
             Some("solve") => AppArguments::Solve {
                 day: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 dhat: args.contains("--dhat"),
+                alloc: args.contains("--alloc"),
+                input: args.opt_value_from_str("--input")?,
+                all_inputs: args.contains("--all-inputs"),
+                example: parse_example(&mut args)?,
             },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
@@ -102,38 +224,103 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
+            AppArguments::All {
+                release,
+                jobs,
+                limits,
+                alloc,
+            } => {
+                all::handle(release, jobs, limits, alloc, registry::SOLUTIONS);
+            }
+            AppArguments::Verify {
+                release,
+                jobs,
+                limits,
+                alloc,
+            } => {
+                verify::handle(release, jobs, limits, alloc, registry::SOLUTIONS);
+            }
+            AppArguments::Time {
+                day,
+                all,
+                store,
+                accept,
+                threshold,
+                jobs,
+                bench_time,
+                limits,
+                alloc,
+            } => {
+                let store = store.then(|| time::StoreOptions {
+                    accept,
+                    threshold: threshold.unwrap_or(time::DEFAULT_THRESHOLD),
+                });
+                let default_options = RunOptions::default();
+                let options = RunOptions {
+                    bench_time: bench_time.unwrap_or(default_options.bench_time),
+                    limits,
+                    count_allocs: alloc,
+                    ..default_options
+                };
+                time::handle(day, all, store, jobs, options, registry::SOLUTIONS);
+            }
+            AppArguments::TimeHistory { day } => time::history(day),
+            AppArguments::Download { day, force } => download::handle(day, force),
             AppArguments::Read { day } => read::handle(day),
             AppArguments::Scaffold {
                 day,
                 download,
                 overwrite,
+                template,
+                dry_run,
             } => {
-                scaffold::handle(day, overwrite);
-                if download {
-                    download::handle(day);
+                scaffold::handle(day, overwrite, template.as_deref(), dry_run);
+                if download && dry_run {
+                    println!("Dry run, not downloading the puzzle.");
+                } else if download {
+                    download::handle(day, false);
+                    examples::handle(day, None, false);
                 }
             }
+            AppArguments::Examples {
+                day,
+                blocks,
+                overwrite,
+            } => examples::handle(day, blocks.as_deref(), overwrite),
+            AppArguments::Harvest { overwrite } => harvest::handle(overwrite),
             AppArguments::Solve {
                 day,
                 release,
                 dhat,
+                alloc,
+                input,
+                all_inputs,
+                example,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+            } => {
+                let options = RunOptions {
+                    submit,
+                    count_allocs: alloc,
+                    input,
+                    all_inputs,
+                    example,
+                    ..RunOptions::default()
+                };
+                solve::handle(day, release, dhat, &options);
+            }
             #[cfg(feature = "today")]
             AppArguments::Today => {
                 match Day::today() {
                     Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
+                        scaffold::handle(day, false, None, false);
+                        download::handle(day, false);
                         read::handle(day)
                     }
                     None => {
                         eprintln!(
                             "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
+                            the {}th of december. Please use `scaffold` with a specific day.",
+                            Year::current_last_day()
                         );
                         process::exit(1)
                     }
@@ -0,0 +1,224 @@
+/// Lightweight allocation counting, enabled with the `alloc-count` feature.
+/// Solution bins install `CountingAllocator` as their global allocator, the runner measures each part with `measure`.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    collections::HashMap,
+    fmt::Display,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use tinyjson::JsonValue;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Global allocator that counts allocations and tracks live bytes, delegating to the system allocator.
+pub struct CountingAllocator;
+
+fn record_alloc(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    BYTES.fetch_add(size, Ordering::Relaxed);
+    let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            // a reallocation counts as a new allocation of `new_size` that replaces the old one.
+            LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Allocations made while running a solution part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations, including reallocations.
+    pub allocations: u64,
+    /// Total bytes allocated.
+    pub bytes: u64,
+    /// Maximum number of bytes that were live at the same time, on top of those live before the part ran.
+    pub peak: u64,
+}
+
+/// Whether solutions in this build count their allocations.
+pub const fn is_enabled() -> bool {
+    cfg!(all(feature = "alloc-count", not(feature = "dhat-heap")))
+}
+
+/// Run `func` and count the allocations it makes. Returns `None` if allocation counting is disabled.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !is_enabled() {
+        return (func(), None);
+    }
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+
+    let result = func();
+
+    let stats = AllocStats {
+        allocations: (ALLOCATIONS.load(Ordering::Relaxed) - allocations) as u64,
+        bytes: (BYTES.load(Ordering::Relaxed) - bytes) as u64,
+        peak: (PEAK.load(Ordering::Relaxed) - live) as u64,
+    };
+
+    (result, Some(stats))
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.5 KiB`.
+#[allow(clippy::cast_precision_loss)]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64;
+    let mut unit = "B";
+
+    for next in UNITS {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = next;
+    }
+
+    format!("{value:.1} {unit}")
+}
+
+impl AllocStats {
+    /// Short summary for tables, e.g. `12 allocs, 1.5 KiB peak`.
+    pub fn format_short(&self) -> String {
+        format!(
+            "{} allocs, {} peak",
+            self.allocations,
+            format_bytes(self.peak)
+        )
+    }
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocs, {} allocated, {} peak",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak)
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&AllocStats> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &AllocStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "allocations".into(),
+            JsonValue::Number(value.allocations as f64),
+        );
+        map.insert("bytes".into(), JsonValue::Number(value.bytes as f64));
+        map.insert("peak".into(), JsonValue::Number(value.peak as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for AllocStats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected allocs to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected allocs.{key} to be a number."))
+        };
+
+        Ok(AllocStats {
+            allocations: number("allocations")? as u64,
+            bytes: number("bytes")? as u64,
+            peak: number("peak")? as u64,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{format_bytes, AllocStats};
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn formats_stats() {
+        let stats = AllocStats {
+            allocations: 12,
+            bytes: 4096,
+            peak: 2048,
+        };
+        assert_eq!(
+            stats.to_string(),
+            "12 allocs, 4.0 KiB allocated, 2.0 KiB peak"
+        );
+        assert_eq!(stats.format_short(), "12 allocs, 2.0 KiB peak");
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let stats = AllocStats {
+            allocations: 1,
+            bytes: 2,
+            peak: 3,
+        };
+        let parsed = AllocStats::try_from(&JsonValue::from(&stats)).unwrap();
+        assert_eq!(parsed, stats);
+    }
+}
@@ -0,0 +1,420 @@
+use std::{collections::HashMap, fmt::Display, fs, io::Error, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{data_dir, Day};
+
+/// Stored in the data directory of the current year.
+static ANSWERS_FILE_NAME: &str = "answers.json";
+
+/// Represents the verified answers for a single day.
+#[derive(Clone, Debug)]
+pub struct Answer {
+    pub day: Day,
+    /// Name of the input in `data/inputs/NN/` these answers belong to, `None` for the default input.
+    pub input: Option<String>,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// Represents the verified answers for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<Answer>,
+}
+
+/// Outcome of comparing a solution result against the answer registry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verification {
+    /// The result matches the stored answer.
+    Correct,
+    /// The result differs from the stored answer.
+    Incorrect { expected: String },
+    /// There is no stored answer for this part yet.
+    Unknown,
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(data_dir().join(ANSWERS_FILE_NAME))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns empty answers.
+    /// A file that can't be read is an error, so that results are never checked against, or stored over, a broken registry.
+    pub fn read_from_file() -> Result<Self, String> {
+        let path = data_dir().join(ANSWERS_FILE_NAME);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(Answers::try_from)
+            .map_err(|e| format!("could not read `{}`: {e}", path.display()))
+    }
+
+    /// Returns the stored answer for a part of a day, if any.
+    pub fn get(&self, day: Day, part: u8) -> Option<&str> {
+        self.get_for_input(day, None, part)
+    }
+
+    /// Returns the stored answer for a part of a day for a named input, if any.
+    pub fn get_for_input(&self, day: Day, input: Option<&str>, part: u8) -> Option<&str> {
+        let answer = self
+            .data
+            .iter()
+            .find(|a| a.day == day && a.input.as_deref() == input)?;
+        match part {
+            1 => answer.part_1.as_deref(),
+            2 => answer.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Store the answer for a part of a day, replacing a previous answer.
+    pub fn set(&mut self, day: Day, part: u8, answer: String) {
+        let index = match self
+            .data
+            .iter()
+            .position(|a| a.day == day && a.input.is_none())
+        {
+            Some(index) => index,
+            None => {
+                self.data.push(Answer {
+                    day,
+                    input: None,
+                    part_1: None,
+                    part_2: None,
+                });
+                self.data.len() - 1
+            }
+        };
+
+        match part {
+            1 => self.data[index].part_1 = Some(answer),
+            2 => self.data[index].part_2 = Some(answer),
+            _ => {}
+        }
+    }
+
+    /// Compare a solution result against the stored answer for a part of a day.
+    pub fn verify(&self, day: Day, part: u8, result: Option<&str>) -> Verification {
+        self.verify_for_input(day, None, part, result)
+    }
+
+    /// Compare a solution result against the stored answer for a part of a day for a named input.
+    pub fn verify_for_input(
+        &self,
+        day: Day,
+        input: Option<&str>,
+        part: u8,
+        result: Option<&str>,
+    ) -> Verification {
+        match self.get_for_input(day, input, part) {
+            None => Verification::Unknown,
+            Some(expected) if result == Some(expected) => Verification::Correct,
+            Some(expected) => Verification::Incorrect {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+/// Format a verification as a table cell.
+pub fn format_cell(verification: &Verification, has_result: bool) -> String {
+    match verification {
+        // parts without a result and without a stored answer have not been solved yet.
+        Verification::Unknown if !has_result => "-".into(),
+        _ => verification.to_string(),
+    }
+}
+
+impl Display for Verification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verification::Correct => write!(f, "✓"),
+            Verification::Incorrect { .. } => write!(f, "✗"),
+            Verification::Unknown => write!(f, "new"),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(Answer::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answer> for JsonValue {
+    fn from(value: &Answer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        if let Some(input) = &value.input {
+            map.insert("input".into(), JsonValue::String(input.clone()));
+        }
+
+        map.insert(
+            "part_1".into(),
+            value
+                .part_1
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        map.insert(
+            "part_2".into(),
+            value
+                .part_2
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Answer {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answer to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answer.day to be a Day struct.")?;
+
+        // NOTE: answers for the default input do not have this key.
+        let input = match json.get("input") {
+            Some(v) if !v.is_null() => Some(
+                v.get::<String>()
+                    .ok_or("Expected answer.input to be null or string.")?,
+            ),
+            _ => None,
+        };
+
+        let part_1 = json
+            .get("part_1")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_1 to be null or string.")?;
+
+        let part_2 = json
+            .get("part_2")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_2 to be null or string.")?;
+
+        Ok(Answer {
+            day,
+            input: input.cloned(),
+            part_1: part_1.cloned(),
+            part_2: part_2.cloned(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::day;
+
+    use super::{Answer, Answers};
+
+    fn get_mock_answers() -> Answers {
+        Answers {
+            data: vec![
+                Answer {
+                    day: day!(1),
+                    input: None,
+                    part_1: Some("1234".into()),
+                    part_2: Some("5678".into()),
+                },
+                Answer {
+                    day: day!(3),
+                    input: None,
+                    part_1: Some("abc".into()),
+                    part_2: None,
+                },
+                Answer {
+                    day: day!(1),
+                    input: Some("alice".into()),
+                    part_1: Some("4321".into()),
+                    part_2: None,
+                },
+            ],
+        }
+    }
+
+    mod deserialization {
+        use crate::{day, template::answers::Answers};
+
+        #[test]
+        fn handles_json_answers() {
+            let json =
+                r#"{ "data": [{ "day": "01", "part_1": "42", "part_2": null }] }"#.to_string();
+            let answers = Answers::try_from(json).unwrap();
+            assert_eq!(answers.data.len(), 1);
+            let answer = answers.data.first().unwrap();
+            assert_eq!(answer.day, day!(1));
+            assert_eq!(answer.part_1, Some("42".to_string()));
+            assert_eq!(answer.part_2, None);
+            assert_eq!(answer.input, None);
+        }
+
+        #[test]
+        fn handles_named_inputs() {
+            let json = r#"{ "data": [{ "day": "01", "input": "alice", "part_1": "42", "part_2": null }] }"#.to_string();
+            let answers = Answers::try_from(json).unwrap();
+            assert_eq!(answers.data[0].input, Some("alice".to_string()));
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_malformed_answers() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": 42 }] }"#.to_string();
+            Answers::try_from(json).unwrap();
+        }
+    }
+
+    mod serialization {
+        use super::get_mock_answers;
+        use crate::template::answers::Answers;
+        use tinyjson::JsonValue;
+
+        #[test]
+        fn roundtrips_answers() {
+            let answers = get_mock_answers();
+            let json = JsonValue::from(answers).stringify().unwrap();
+            let parsed = Answers::try_from(json).unwrap();
+            assert_eq!(parsed.data.len(), 3);
+            assert_eq!(parsed.data[1].part_1, Some("abc".to_string()));
+            assert_eq!(parsed.data[1].part_2, None);
+            assert_eq!(parsed.data[2].input, Some("alice".to_string()));
+        }
+    }
+
+    mod verify {
+        use super::get_mock_answers;
+        use crate::{day, template::answers::Verification};
+
+        #[test]
+        fn handles_correct_answers() {
+            let answers = get_mock_answers();
+            assert_eq!(
+                answers.verify(day!(1), 1, Some("1234")),
+                Verification::Correct
+            );
+        }
+
+        #[test]
+        fn handles_changed_answers() {
+            let answers = get_mock_answers();
+            assert_eq!(
+                answers.verify(day!(1), 2, Some("5679")),
+                Verification::Incorrect {
+                    expected: "5678".into()
+                }
+            );
+            assert_eq!(
+                answers.verify(day!(1), 2, None),
+                Verification::Incorrect {
+                    expected: "5678".into()
+                }
+            );
+        }
+
+        #[test]
+        fn handles_named_inputs() {
+            let answers = get_mock_answers();
+            assert_eq!(
+                answers.verify_for_input(day!(1), Some("alice"), 1, Some("4321")),
+                Verification::Correct
+            );
+            assert_eq!(
+                answers.verify_for_input(day!(1), Some("alice"), 2, Some("5678")),
+                Verification::Unknown
+            );
+            assert_eq!(
+                answers.verify_for_input(day!(1), Some("bob"), 1, Some("1234")),
+                Verification::Unknown
+            );
+            assert_eq!(
+                answers.verify(day!(1), 1, Some("4321")),
+                Verification::Incorrect {
+                    expected: "1234".into()
+                }
+            );
+        }
+
+        #[test]
+        fn handles_unknown_answers() {
+            let answers = get_mock_answers();
+            assert_eq!(answers.verify(day!(3), 2, Some("x")), Verification::Unknown);
+            assert_eq!(answers.verify(day!(2), 1, Some("x")), Verification::Unknown);
+        }
+    }
+
+    mod set {
+        use super::get_mock_answers;
+        use crate::day;
+
+        #[test]
+        fn replaces_answers_of_the_default_input() {
+            let mut answers = get_mock_answers();
+            answers.set(day!(1), 2, "9999".into());
+            assert_eq!(answers.get(day!(1), 2), Some("9999"));
+            assert_eq!(answers.get_for_input(day!(1), Some("alice"), 2), None);
+            assert_eq!(answers.data.len(), 3);
+        }
+
+        #[test]
+        fn adds_new_days() {
+            let mut answers = get_mock_answers();
+            answers.set(day!(5), 1, "42".into());
+            assert_eq!(answers.get(day!(5), 1), Some("42"));
+            assert_eq!(answers.get(day!(5), 2), None);
+        }
+    }
+}
@@ -1,10 +1,11 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
     fmt::Display,
+    fs,
     process::{Command, Output, Stdio},
 };
 
-use crate::template::Day;
+use crate::template::{data_dir, Day, Year};
 
 #[derive(Debug)]
 pub enum AocCommandError {
@@ -53,6 +54,11 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
     let input_path = get_input_path(day);
     let puzzle_path = get_puzzle_path(day);
 
+    // years other than `AOC_YEAR` might not have their data directories yet.
+    for dir in ["inputs", "puzzles"] {
+        let _ = fs::create_dir_all(data_dir().join(dir));
+    }
+
     let args = build_args(
         "download",
         &[
@@ -72,33 +78,68 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
     Ok(output)
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
+/// Download the puzzle description again, e.g. to add part two once part one is solved.
+pub fn download_puzzle(day: Day) -> Result<Output, AocCommandError> {
+    let args = build_args(
+        "download",
+        &[
+            "--overwrite".into(),
+            "--puzzle-only".into(),
+            "--puzzle-file".into(),
+            get_puzzle_path(day),
+        ],
+        day,
+    );
+
+    call_aoc_cli(&args)
+}
+
+/// Submit an answer, returns the response of the server as printed by aoc-cli.
+pub fn submit(day: Day, part: u8, result: &str) -> Result<String, AocCommandError> {
     // workaround: the argument order is inverted for submit.
     let mut args = build_args("submit", &[], day);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
-}
 
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
+    // the response is needed to tell whether the answer was accepted.
+    let output = Command::new("aoc")
+        .args(&args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|_| AocCommandError::CommandNotCallable)?;
+
+    let response = String::from_utf8_lossy(&output.stdout).into_owned();
+    print!("{response}");
+
+    // a response of the server is classified even if aoc-cli reports a failure.
+    if output.status.success() || !response.trim().is_empty() {
+        Ok(response)
+    } else {
+        Err(AocCommandError::BadExitStatus(output))
+    }
 }
 
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
+pub fn get_input_path(day: Day) -> String {
+    data_dir()
+        .join("inputs")
+        .join(format!("{day}.txt"))
+        .display()
+        .to_string()
 }
 
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
-    }
+pub fn get_puzzle_path(day: Day) -> String {
+    data_dir()
+        .join("puzzles")
+        .join(format!("{day}.md"))
+        .display()
+        .to_string()
 }
 
 fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
     let mut cmd_args = args.to_vec();
 
-    if let Some(year) = get_year() {
+    if let Some(year) = Year::current() {
         cmd_args.push("--year".into());
         cmd_args.push(year.to_string());
     }
@@ -0,0 +1,318 @@
+/// Built-in client for adventofcode.com, used instead of aoc-cli when it is not installed or `AOC_CLIENT=native`.
+/// Reads the session cookie from `AOC_SESSION` or the file aoc-cli uses, `~/.adventofcode.session`.
+/// The server can be changed with `AOC_BASE_URL`, e.g. to test against a local stand-in.
+/// Requests identify themselves with `AOC_USER_AGENT`, which should say who to contact about them.
+use std::{env, fmt::Display, fs, io, path::PathBuf};
+
+use crate::template::aoc_cli::{self, get_input_path, get_puzzle_path};
+use crate::template::markdown::html_to_markdown;
+use crate::template::{data_dir, Day, Year};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const SESSION_FILE_NAME: &str = ".adventofcode.session";
+const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    YearNotConfigured,
+    Http(String),
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set `AOC_SESSION` or store it in `~/{SESSION_FILE_NAME}`."
+            ),
+            AocClientError::YearNotConfigured => {
+                write!(f, "no year configured. Set `AOC_YEAR` or pass `--year`.")
+            }
+            AocClientError::Http(e) => write!(f, "{e}"),
+            AocClientError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+/// Whether to use the built-in client. `AOC_CLIENT` picks `native` or `aoc-cli`, otherwise aoc-cli is preferred if it is installed.
+pub fn is_preferred() -> bool {
+    match env::var("AOC_CLIENT").as_deref() {
+        Ok("native") => true,
+        Ok("aoc-cli") => false,
+        _ => aoc_cli::check().is_err(),
+    }
+}
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, user_agent: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(user_agent).build(),
+        }
+    }
+
+    /// A client for `AOC_BASE_URL` or adventofcode.com, with the session cookie of the user.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let var = |name: &str| env::var(name).ok().filter(|value| !value.is_empty());
+
+        let base_url = var("AOC_BASE_URL").unwrap_or_else(|| DEFAULT_BASE_URL.into());
+        let user_agent = var("AOC_USER_AGENT").unwrap_or_else(|| DEFAULT_USER_AGENT.into());
+
+        Ok(Self::new(&base_url, &read_session()?, &user_agent))
+    }
+
+    /// The puzzle input of the user.
+    pub fn input(&self, year: Year, day: Day) -> Result<String, AocClientError> {
+        self.get(&format!("/{year}/day/{}/input", day.into_inner()))
+    }
+
+    /// The puzzle description as markdown, including the answers of solved parts.
+    pub fn puzzle(&self, year: Year, day: Day) -> Result<String, AocClientError> {
+        let html = self.get(&format!("/{year}/day/{}", day.into_inner()))?;
+        Ok(html_to_markdown(&html))
+    }
+
+    /// Submit an answer, returns the response of the server as markdown.
+    pub fn submit(
+        &self,
+        year: Year,
+        day: Day,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, AocClientError> {
+        let url = format!("{}/{year}/day/{}/answer", self.base_url, day.into_inner());
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        let html = read_response(&url, response)?;
+        Ok(html_to_markdown(&html))
+    }
+
+    fn get(&self, path: &str) -> Result<String, AocClientError> {
+        let url = format!("{}{path}", self.base_url);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+
+        read_response(&url, response)
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, AocClientError> {
+    match response {
+        Ok(response) => Ok(response.into_string()?),
+        Err(ureq::Error::Status(status, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            let reason = body.lines().next().unwrap_or_default().trim();
+            Err(AocClientError::Http(format!(
+                "request to {url} failed with status {status}: {reason}"
+            )))
+        }
+        Err(e) => Err(AocClientError::Http(format!(
+            "request to {url} failed: {e}"
+        ))),
+    }
+}
+
+fn read_session() -> Result<String, AocClientError> {
+    if let Some(session) = env::var("AOC_SESSION").ok().filter(|s| !s.is_empty()) {
+        return Ok(session);
+    }
+
+    let path = env::var_os("HOME")
+        .map(PathBuf::from)
+        .ok_or(AocClientError::SessionNotFound)?
+        .join(SESSION_FILE_NAME);
+
+    fs::read_to_string(path)
+        .ok()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(AocClientError::SessionNotFound)
+}
+
+fn current_year() -> Result<Year, AocClientError> {
+    Year::current().ok_or(AocClientError::YearNotConfigured)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Download the puzzle description again, e.g. to add part two once part one is solved.
+pub fn download_puzzle(day: Day) -> Result<String, AocClientError> {
+    let puzzle = Client::from_env()?.puzzle(current_year()?, day)?;
+    fs::create_dir_all(data_dir().join("puzzles"))?;
+    fs::write(get_puzzle_path(day), &puzzle)?;
+    Ok(puzzle)
+}
+
+pub fn read(day: Day) -> Result<(), AocClientError> {
+    println!("{}", download_puzzle(day)?);
+    Ok(())
+}
+
+pub fn download(day: Day) -> Result<(), AocClientError> {
+    let client = Client::from_env()?;
+    let year = current_year()?;
+
+    let input_path = get_input_path(day);
+    let puzzle_path = get_puzzle_path(day);
+
+    let input = client.input(year, day)?;
+    let puzzle = client.puzzle(year, day)?;
+
+    for dir in ["inputs", "puzzles"] {
+        fs::create_dir_all(data_dir().join(dir))?;
+    }
+    fs::write(&input_path, input)?;
+    fs::write(&puzzle_path, puzzle)?;
+
+    println!("---");
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(())
+}
+
+pub fn submit(day: Day, part: u8, result: &str) -> Result<String, AocClientError> {
+    let response = Client::from_env()?.submit(current_year()?, day, part, result)?;
+    println!("{response}");
+    Ok(response)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    use super::{AocClientError, Client};
+    use crate::template::{Day, Year};
+
+    /// A stand-in for the server that answers each request with the next response and returns the requests.
+    fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = vec![];
+
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                        length = value.trim().parse().unwrap();
+                    }
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    request.push_str(&line);
+                }
+
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                requests.push(request);
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+
+            requests
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn downloads_inputs_and_puzzles() {
+        let (url, server) = serve(vec![
+            (200, "1 2\n3 4\n"),
+            (
+                200,
+                "<html><main><article><h2>--- Day 5 ---</h2><p>Hi</p></article></main></html>",
+            ),
+        ]);
+        let client = Client::new(&format!("{url}/"), "secret\n", "me@example.com");
+        let year = Year::new(2023).unwrap();
+        let day = Day::new(5).unwrap();
+
+        assert_eq!(client.input(year, day).unwrap(), "1 2\n3 4\n");
+        assert_eq!(
+            client.puzzle(year, day).unwrap(),
+            "\\--- Day 5 ---\n----------\n\nHi\n"
+        );
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2023/day/5/input HTTP/1.1"));
+        assert!(requests[0].contains("session=secret\r\n"));
+        assert!(requests[0].contains("me@example.com\r\n"));
+        assert!(requests[1].starts_with("GET /2023/day/5 HTTP/1.1"));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (url, server) = serve(vec![(
+            200,
+            "<main><article><p>That's the right answer!</p></article></main>",
+        )]);
+        let client = Client::new(&url, "secret", "me@example.com");
+
+        let response = client
+            .submit(Year::new(2023).unwrap(), Day::new(5).unwrap(), 2, "42")
+            .unwrap();
+        assert_eq!(response, "That's the right answer!\n");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2023/day/5/answer HTTP/1.1"));
+        assert!(requests[0].ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn reports_failed_requests() {
+        let (url, server) = serve(vec![(400, "Puzzle inputs differ by user.\n")]);
+        let client = Client::new(&url, "expired", "me@example.com");
+
+        let error = client
+            .input(Year::new(2023).unwrap(), Day::new(5).unwrap())
+            .unwrap_err();
+        assert!(matches!(error, AocClientError::Http(_)));
+        assert!(error
+            .to_string()
+            .ends_with("failed with status 400: Puzzle inputs differ by user."));
+
+        server.join().unwrap();
+    }
+}
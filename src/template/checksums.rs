@@ -0,0 +1,188 @@
+/// Checksums of downloaded puzzle inputs.
+/// Inputs never change, so a different checksum on a later download hints at a wrong session or year.
+use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{data_dir, Day};
+
+/// Stored in the data directory of the current year.
+static CHECKSUMS_FILE_NAME: &str = "checksums.json";
+
+/// FNV-1a hash of the content as hex. Stable across platforms and compiler versions, unlike `DefaultHasher`.
+pub fn checksum(content: &str) -> String {
+    let hash = content
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+    format!("{hash:016x}")
+}
+
+/// Represents the checksum of the input of a single day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Checksum {
+    pub day: Day,
+    pub checksum: String,
+}
+
+/// Represents the checksums of the inputs of a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Checksums {
+    pub data: Vec<Checksum>,
+}
+
+impl Checksums {
+    /// Dehydrate checksums to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(data_dir().join(CHECKSUMS_FILE_NAME))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate checksums from a JSON file. If not present, returns no checksums.
+    /// A file that can't be read is an error, so that it is never overwritten with the checksums of a single day.
+    pub fn read_from_file() -> Result<Self, String> {
+        let path = data_dir().join(CHECKSUMS_FILE_NAME);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(Checksums::try_from)
+            .map_err(|e| format!("could not read `{}`: {e}", path.display()))
+    }
+
+    /// Returns the recorded checksum of the input of a day, if any.
+    pub fn get(&self, day: Day) -> Option<&str> {
+        self.data
+            .iter()
+            .find(|c| c.day == day)
+            .map(|c| c.checksum.as_str())
+    }
+
+    /// Record the checksum of the input of a day, replacing a previous checksum.
+    pub fn set(&mut self, day: Day, checksum: String) {
+        match self.data.iter_mut().find(|c| c.day == day) {
+            Some(entry) => entry.checksum = checksum,
+            None => self.data.push(Checksum { day, checksum }),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Checksums> for JsonValue {
+    fn from(value: Checksums) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Checksums {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Checksums {
+            data: json_data
+                .iter()
+                .map(Checksum::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Checksum> for JsonValue {
+    fn from(value: &Checksum) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("checksum".into(), JsonValue::String(value.checksum.clone()));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Checksum {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected checksum to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected checksum.day to be a Day struct.")?;
+
+        let checksum = json
+            .get("checksum")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected checksum.checksum to be a string.")?;
+
+        Ok(Checksum {
+            day,
+            checksum: checksum.clone(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{checksum, Checksums};
+    use crate::day;
+
+    #[test]
+    fn computes_stable_checksums() {
+        assert_eq!(checksum(""), "cbf29ce484222325");
+        assert_eq!(checksum("a"), "af63dc4c8601ec8c");
+        assert_ne!(checksum("1\n2\n"), checksum("1\n2"));
+    }
+
+    #[test]
+    fn replaces_checksums() {
+        let mut checksums = Checksums::default();
+        checksums.set(day!(1), "a".into());
+        checksums.set(day!(2), "b".into());
+        checksums.set(day!(1), "c".into());
+
+        assert_eq!(checksums.get(day!(1)), Some("c"));
+        assert_eq!(checksums.get(day!(2)), Some("b"));
+        assert_eq!(checksums.get(day!(3)), None);
+    }
+
+    #[test]
+    fn roundtrips_checksums() {
+        let mut checksums = Checksums::default();
+        checksums.set(day!(1), "af63dc4c8601ec8c".into());
+
+        let json = tinyjson::JsonValue::from(checksums.clone())
+            .stringify()
+            .unwrap();
+        let parsed = Checksums::try_from(json).unwrap();
+        assert_eq!(parsed.data, checksums.data);
+    }
+}
@@ -1,5 +1,22 @@
+use crate::template::runner::{Limits, RunOptions, Solution};
 use crate::template::{all_days, run_multi::run_multi};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+pub fn handle(
+    is_release: bool,
+    jobs: Option<usize>,
+    limits: Limits,
+    count_allocs: bool,
+    solutions: &[Solution],
+) {
+    run_multi(
+        &all_days().collect(),
+        is_release,
+        &RunOptions {
+            limits,
+            count_allocs,
+            ..RunOptions::default()
+        },
+        jobs,
+        solutions,
+    );
 }
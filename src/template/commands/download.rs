@@ -1,14 +1,94 @@
-use crate::template::{aoc_cli, Day};
-use std::process;
+use crate::template::aoc_cli::{get_input_path, get_puzzle_path};
+use crate::template::checksums::{checksum, Checksums};
+use crate::template::{aoc_cli, aoc_client, Day};
+use std::{fs, process};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
+/// Download the input and puzzle description of a day.
+/// Inputs never change, so an existing input is kept unless `force` is set. The puzzle description is always updated.
+pub fn handle(day: Day, force: bool) {
+    let is_native = aoc_client::is_preferred();
+
+    if !is_native && aoc_cli::check().is_err() {
         eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    let input_path = get_input_path(day);
+    let previous = fs::read_to_string(&input_path)
+        .ok()
+        .filter(|input| !input.is_empty());
+
+    // checksums that can't be read are neither checked nor overwritten.
+    let mut checksums = Checksums::read_from_file()
+        .map_err(|e| eprintln!("Warning: not checking or storing the input checksum: {e}"))
+        .ok();
+
+    if let (Some(previous), false) = (&previous, force) {
+        println!("Input \"{input_path}\" already exists, not downloading it again. Pass `--force` to download it anyway.");
+
+        // inputs downloaded before checksums were recorded.
+        if let Some(checksums) = checksums.as_mut().filter(|c| c.get(day).is_none()) {
+            checksums.set(day, checksum(previous));
+            store_checksums(checksums);
+        }
+
+        let result = if is_native {
+            aoc_client::download_puzzle(day)
+                .map(|_| ())
+                .map_err(|e| e.to_string())
+        } else {
+            aoc_cli::download_puzzle(day)
+                .map(|_| ())
+                .map_err(|e| e.to_string())
+        };
+
+        if let Err(e) = result {
+            eprintln!("failed to download the puzzle description: {e}");
+            process::exit(1);
+        }
+
+        println!(
+            "🎄 Successfully wrote puzzle to \"{}\".",
+            get_puzzle_path(day)
+        );
+        return;
+    }
+
+    let result = if is_native {
+        aoc_client::download(day).map_err(|e| format!("failed to download: {e}"))
+    } else {
+        aoc_cli::download(day)
+            .map(|_| ())
+            .map_err(|e| format!("failed to call aoc-cli: {e}"))
+    };
+
+    if let Err(e) = result {
+        eprintln!("{e}");
         process::exit(1);
+    }
+
+    let (Ok(input), Some(mut checksums)) = (fs::read_to_string(&input_path), checksums) else {
+        return;
     };
+
+    let downloaded = checksum(&input);
+    let recorded = checksums
+        .get(day)
+        .map(String::from)
+        .or_else(|| previous.as_deref().map(checksum));
+
+    if let Some(recorded) = recorded.filter(|recorded| *recorded != downloaded) {
+        eprintln!(
+            "⚠️ The downloaded input differs from the previous one (checksum {downloaded}, previously {recorded}). Check the session cookie and the year."
+        );
+    }
+
+    checksums.set(day, downloaded);
+    store_checksums(&checksums);
+}
+
+fn store_checksums(checksums: &Checksums) {
+    if let Err(e) = checksums.store_file() {
+        eprintln!("Failed to store input checksums: {e}");
+    }
 }
@@ -0,0 +1,237 @@
+use std::{
+    fs,
+    io::{self, IsTerminal, Write},
+    path::Path,
+    process,
+    time::SystemTime,
+};
+
+use crate::template::aoc_cli::get_puzzle_path;
+use crate::template::examples::{Example, ExampleManifest, Expected};
+use crate::template::puzzle::{
+    extract_code_blocks, find_example_answers, parse_selection, CodeBlock, ExampleAnswer,
+};
+use crate::template::{data_dir, Day};
+
+/// Write code blocks of the downloaded puzzle description to `data/examples`.
+/// Blocks are picked with `selection`, interactively, or by suggestion if stdin is not a terminal.
+/// A single block is written to `NN.txt`, several blocks to `NN-1.txt`, `NN-2.txt` and so on.
+/// Example answers stated in the description are added to the example manifest `NN.json`.
+pub fn handle(day: Day, selection: Option<&str>, overwrite: bool) {
+    let puzzle_path = get_puzzle_path(day);
+
+    let Ok(markdown) = fs::read_to_string(&puzzle_path) else {
+        eprintln!("Puzzle description \"{puzzle_path}\" not found. Try running `cargo download {day}` first.");
+        process::exit(1);
+    };
+
+    let blocks = extract_code_blocks(&markdown);
+
+    if blocks.is_empty() {
+        println!("No code blocks found in \"{puzzle_path}\".");
+        return;
+    }
+
+    for (i, block) in blocks.iter().enumerate() {
+        let marker = if block.is_suggested { " (example)" } else { "" };
+        println!("[{}]{marker}", i + 1);
+        println!("{}", block.preview(5));
+    }
+    println!("---");
+
+    let selected = match selection {
+        Some(selection) => parse_selection(selection, blocks.len()),
+        None if io::stdin().is_terminal() => prompt_selection(&blocks),
+        None => Ok(suggested(&blocks)),
+    };
+
+    let selected = selected.unwrap_or_else(|e| {
+        eprintln!("Invalid selection: {e}");
+        process::exit(1);
+    });
+
+    if selected.is_empty() {
+        println!("No blocks selected.");
+        return;
+    }
+
+    let mut written: Vec<(String, &CodeBlock)> = vec![];
+
+    for (i, index) in selected.iter().enumerate() {
+        let file = if selected.len() == 1 {
+            format!("{day}.txt")
+        } else {
+            format!("{day}-{}.txt", i + 1)
+        };
+
+        let path = data_dir().join("examples").join(&file);
+        if write_example(&path.display().to_string(), &blocks[*index], overwrite) {
+            written.push((file, &blocks[*index]));
+        }
+    }
+
+    update_manifest(day, &written, &find_example_answers(&markdown), overwrite);
+
+    let example_arg = if selected.len() == 1 { "" } else { " 1" };
+    println!("---");
+    println!(
+        "🎄 Type `cargo solve {day} --example{example_arg}` to run your solution on an example."
+    );
+}
+
+fn suggested(blocks: &[CodeBlock]) -> Vec<usize> {
+    (0..blocks.len())
+        .filter(|i| blocks[*i].is_suggested)
+        .collect()
+}
+
+fn prompt_selection(blocks: &[CodeBlock]) -> Result<Vec<usize>, String> {
+    let suggested = suggested(blocks);
+    let default = suggested
+        .iter()
+        .map(|i| (i + 1).to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    print!("Which blocks are examples? [{default}]: ");
+    let _ = io::stdout().flush();
+
+    let mut answer = String::new();
+    io::stdin()
+        .read_line(&mut answer)
+        .map_err(|e| e.to_string())?;
+
+    if answer.trim().is_empty() {
+        Ok(suggested)
+    } else {
+        parse_selection(&answer, blocks.len())
+    }
+}
+
+/// Examples that were already filled in are only replaced with `--overwrite`.
+/// Empty files, e.g. those created by `cargo scaffold`, are always replaced.
+/// Returns whether the file contains the block afterwards.
+fn write_example(path: &str, block: &CodeBlock, overwrite: bool) -> bool {
+    let existing = fs::read_to_string(Path::new(path)).unwrap_or_default();
+
+    if existing == block.content {
+        println!("Example file \"{path}\" is up to date");
+        return true;
+    }
+
+    if !existing.is_empty() && !overwrite {
+        eprintln!("Skipped \"{path}\": the file is not empty. Pass `--overwrite` to replace it.");
+        return false;
+    }
+
+    match fs::write(path, &block.content) {
+        Ok(()) => {
+            println!("Created example file \"{path}\"");
+            true
+        }
+        Err(e) => {
+            eprintln!("Failed to write example file: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Add the example answers to the manifest. Each answer belongs to the last example that is shown before it.
+/// Expected answers that are already in the manifest are only replaced with `--overwrite`.
+fn update_manifest(
+    day: Day,
+    written: &[(String, &CodeBlock)],
+    answers: &[ExampleAnswer],
+    overwrite: bool,
+) {
+    let path = data_dir().join("examples").join(format!("{day}.json"));
+
+    let mut manifest = match ExampleManifest::read_from_file(&path) {
+        Ok(manifest) => manifest.unwrap_or_default(),
+        Err(e) => {
+            eprintln!("Not updating \"{}\": {e}", path.display());
+            return;
+        }
+    };
+
+    let mut is_changed = false;
+
+    for answer in answers {
+        let file = written
+            .iter()
+            .filter_map(|(file, block)| {
+                let position = block.positions.iter().filter(|p| **p < answer.line).max()?;
+                Some((position, file))
+            })
+            .max()
+            .map(|(_, file)| file);
+
+        let Some(file) = file else {
+            continue;
+        };
+
+        let example = match manifest.examples.iter().position(|e| &e.file == file) {
+            Some(index) => &mut manifest.examples[index],
+            None => {
+                manifest.examples.push(Example {
+                    file: file.clone(),
+                    part_1: None,
+                    part_2: None,
+                });
+                manifest.examples.last_mut().unwrap()
+            }
+        };
+
+        let expected = if answer.part == 1 {
+            &mut example.part_1
+        } else {
+            &mut example.part_2
+        };
+
+        if expected.is_some() && !overwrite {
+            continue;
+        }
+
+        *expected = Some(Expected::Answer(answer.answer.clone()));
+        is_changed = true;
+        println!(
+            "Expecting `{}` for part {} of \"{file}\"",
+            answer.answer, answer.part
+        );
+    }
+
+    if !is_changed {
+        return;
+    }
+
+    let is_new = !path.exists();
+
+    match manifest.store_file(&path) {
+        Ok(()) => println!("Updated example manifest \"{}\"", path.display()),
+        Err(e) => {
+            eprintln!("Failed to write example manifest: {e}");
+            process::exit(1);
+        }
+    }
+
+    if is_new {
+        watch_manifest();
+    }
+}
+
+/// The build script only watches manifests that existed when it last ran. Touch it, so that it picks up a new one.
+fn watch_manifest() {
+    let build_script = Path::new(env!("CARGO_MANIFEST_DIR")).join("build.rs");
+
+    let result = fs::File::options()
+        .append(true)
+        .open(&build_script)
+        .and_then(|file| file.set_modified(SystemTime::now()));
+
+    if let Err(e) = result {
+        eprintln!(
+            "Warning: could not touch \"{}\", tests for the new manifest are generated on the next change of the build script: {e}",
+            build_script.display()
+        );
+    }
+}
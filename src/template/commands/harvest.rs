@@ -0,0 +1,53 @@
+use std::{fs, process};
+
+use crate::template::all_days;
+use crate::template::aoc_cli::get_puzzle_path;
+use crate::template::puzzle::find_puzzle_answers;
+use crate::template::runner::read_answers;
+
+/// Fill the answer registry with the accepted answers stated on downloaded puzzle pages.
+/// Stored answers that differ from the puzzle page are only replaced with `--overwrite`.
+pub fn handle(overwrite: bool) {
+    // storing over a registry that can't be read would drop its answers.
+    let mut answers = read_answers();
+    let (mut added, mut conflicts) = (0, 0);
+
+    for day in all_days() {
+        let Ok(markdown) = fs::read_to_string(get_puzzle_path(day)) else {
+            continue;
+        };
+
+        for (i, answer) in find_puzzle_answers(&markdown).into_iter().enumerate() {
+            #[allow(clippy::cast_possible_truncation)]
+            let part = i as u8 + 1;
+
+            match answers.get(day, part) {
+                Some(stored) if stored == answer => continue,
+                Some(stored) if !overwrite => {
+                    eprintln!("Day {day} (Part {part}): stored answer `{stored}` differs from accepted answer `{answer}`.");
+                    conflicts += 1;
+                    continue;
+                }
+                _ => {}
+            }
+
+            println!("Day {day} (Part {part}): {answer}");
+            answers.set(day, part, answer);
+            added += 1;
+        }
+    }
+
+    if added > 0 {
+        if let Err(e) = answers.store_file() {
+            eprintln!("Failed to store answers: {e}");
+            process::exit(1);
+        }
+    }
+
+    println!("---");
+    println!("🎄 Added {added} answer(s) to the answer registry.");
+
+    if conflicts > 0 {
+        eprintln!("{conflicts} stored answer(s) were kept. Pass `--overwrite` to replace them.");
+    }
+}
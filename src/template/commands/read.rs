@@ -1,8 +1,16 @@
 use std::process;
 
-use crate::template::{aoc_cli, Day};
+use crate::template::{aoc_cli, aoc_client, Day};
 
 pub fn handle(day: Day) {
+    if aoc_client::is_preferred() {
+        if let Err(e) = aoc_client::read(day) {
+            eprintln!("failed to read: {e}");
+            process::exit(1);
+        }
+        return;
+    }
+
     if aoc_cli::check().is_err() {
         eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
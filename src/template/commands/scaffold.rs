@@ -1,13 +1,55 @@
 use std::{
-    fs::{File, OpenOptions},
+    env,
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::template::Day;
+use crate::template::{bin_name, bin_path, data_dir, is_configured_year, Day, Year};
 
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+static TEMPLATES_DIR: &str = "src/templates";
+static DEFAULT_TEMPLATE: &str = "default";
+
+/// Name of the template to scaffold with: `--template`, the `AOC_TEMPLATE` environment variable or `default`.
+fn template_name(template: Option<&str>) -> String {
+    template.map(String::from).unwrap_or_else(|| {
+        env::var("AOC_TEMPLATE")
+            .ok()
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| DEFAULT_TEMPLATE.into())
+    })
+}
+
+/// Names of the templates in `src/templates`, sorted.
+fn list_templates() -> Vec<String> {
+    let Ok(entries) = fs::read_dir(TEMPLATES_DIR) else {
+        return vec![];
+    };
+
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "txt" {
+                return None;
+            }
+            Some(path.file_stem()?.to_string_lossy().into_owned())
+        })
+        .collect();
+
+    names.sort();
+    names
+}
+
+fn read_template(name: &str) -> String {
+    let path = Path::new(TEMPLATES_DIR).join(format!("{name}.txt"));
+
+    fs::read_to_string(&path).unwrap_or_else(|e| {
+        eprintln!("Failed to read template \"{}\": {e}", path.display());
+        eprintln!("Available templates: {}", list_templates().join(", "));
+        process::exit(1);
+    })
+}
 
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
@@ -19,61 +61,164 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
-}
+/// Create a data file unless it exists. Data files are never truncated, they might contain a downloaded input or a pasted example.
+fn create_data_file(path: &str, kind: &str, dry_run: bool) {
+    if Path::new(path).exists() {
+        let state = if fs::metadata(path).is_ok_and(|m| m.len() > 0) {
+            "existing"
+        } else {
+            "existing empty"
+        };
+        println!("Kept {state} {kind} file \"{path}\"");
+        return;
+    }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+    if dry_run {
+        println!("Would create empty {kind} file \"{path}\"");
+        return;
+    }
 
-    let mut file = match safe_create_file(&module_path, overwrite) {
-        Ok(file) => file,
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(_) => {
+            println!("Created empty {kind} file \"{path}\"");
+        }
         Err(e) => {
-            eprintln!("Failed to create module file: {e}");
+            eprintln!("Failed to create {kind} file: {e}");
             process::exit(1);
         }
-    };
+    }
+}
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
+/// Cargo only discovers bins in `src/bin/NN.rs`, so bins in per-year directories are added to `Cargo.toml`.
+fn register_bin(day: Day, dry_run: bool) {
+    let (name, path) = (bin_name(day), bin_path(day));
+
+    let manifest = fs::read_to_string("Cargo.toml").unwrap_or_else(|e| {
+        eprintln!("Failed to read Cargo.toml: {e}");
+        process::exit(1);
+    });
+
+    if manifest.contains(&format!("path = \"{path}\"")) {
+        return;
+    }
+
+    if dry_run {
+        println!("Would register bin \"{name}\" in \"Cargo.toml\"");
+        return;
+    }
+
+    let entry = format!("\n[[bin]]\nname = \"{name}\"\npath = \"{path}\"\n");
+
+    let result = OpenOptions::new()
+        .append(true)
+        .open("Cargo.toml")
+        .and_then(|mut file| file.write_all(entry.as_bytes()));
+
+    match result {
+        Ok(()) => println!("Registered bin \"{name}\" in \"Cargo.toml\""),
         Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
+            eprintln!("Failed to register bin \"{name}\" in Cargo.toml: {e}");
             process::exit(1);
         }
     }
+}
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
+/// Create the directories of the current year. The configured year uses the existing flat layout.
+fn create_dirs(module_path: &str, dry_run: bool) {
+    if dry_run || is_configured_year() {
+        return;
+    }
+
+    let dirs = [
+        Path::new(module_path).parent().map(Path::to_path_buf),
+        Some(data_dir().join("inputs")),
+        Some(data_dir().join("examples")),
+    ];
+
+    for dir in dirs.into_iter().flatten() {
+        if let Err(e) = fs::create_dir_all(&dir) {
+            eprintln!("Failed to create directory \"{}\": {e}", dir.display());
             process::exit(1);
         }
     }
+}
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+/// Substitute the day into a template. Solutions of years other than `AOC_YEAR` also state their year.
+fn render_template(template: &str, day: Day) -> String {
+    let template = match Year::current().filter(|_| !is_configured_year()) {
+        Some(year) => template.replace(
+            "solution!(%DAY_NUMBER%)",
+            &format!("solution!(%DAY_NUMBER%, year = {year})"),
+        ),
+        None => template.to_string(),
+    };
+
+    template.replace("%DAY_NUMBER%", &day.into_inner().to_string())
+}
+
+/// Scaffold the module, input and example files of a day of the current year.
+/// With `dry_run`, only reports what would be done.
+pub fn handle(day: Day, overwrite: bool, template: Option<&str>, dry_run: bool) {
+    let module_template = read_template(&template_name(template));
+
+    let input_path = data_dir()
+        .join("inputs")
+        .join(format!("{day}.txt"))
+        .display()
+        .to_string();
+    let example_path = data_dir()
+        .join("examples")
+        .join(format!("{day}.txt"))
+        .display()
+        .to_string();
+    let module_path = bin_path(day);
+
+    create_dirs(&module_path, dry_run);
+
+    if dry_run {
+        match (Path::new(&module_path).exists(), overwrite) {
+            (false, _) => println!("Would create module file \"{module_path}\""),
+            (true, true) => println!("Would overwrite module file \"{module_path}\""),
+            (true, false) => println!(
+                "Module file \"{module_path}\" already exists. Pass `--overwrite` to replace it."
+            ),
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+    } else {
+        let mut file = match safe_create_file(&module_path, overwrite) {
+            Ok(file) => file,
+            Err(e) => {
+                eprintln!("Failed to create module file: {e}");
+                process::exit(1);
+            }
+        };
+
+        match file.write_all(render_template(&module_template, day).as_bytes()) {
+            Ok(()) => {
+                println!("Created module file \"{}\"", &module_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to write module contents: {e}");
+                process::exit(1);
+            }
         }
     }
 
+    if !is_configured_year() {
+        register_bin(day, dry_run);
+    }
+
+    create_data_file(&input_path, "input", dry_run);
+    create_data_file(&example_path, "example", dry_run);
+
+    let year_arg = match Year::current().filter(|_| !is_configured_year()) {
+        Some(year) => format!(" --year {year}"),
+        None => String::new(),
+    };
+
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    if dry_run {
+        println!("🎄 Dry run, no files were changed.");
+    } else {
+        println!("🎄 Type `cargo solve {day}{year_arg}` to run your solution.");
+    }
 }
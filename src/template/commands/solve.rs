@@ -1,9 +1,10 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
 
-use crate::template::Day;
+use crate::template::runner::RunOptions;
+use crate::template::{bin_name, Day};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+pub fn handle(day: Day, release: bool, dhat: bool, options: &RunOptions) {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), bin_name(day)];
 
     if dhat {
         cmd_args.extend([
@@ -16,13 +17,18 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push("--release".to_string());
     }
 
-    cmd_args.push("--".to_string());
-
-    if let Some(submit_part) = submit_part {
-        cmd_args.push("--submit".to_string());
-        cmd_args.push(submit_part.to_string());
+    if options.count_allocs && dhat {
+        eprintln!("Warning: allocations are not counted when profiling with `--dhat`.");
+    } else if options.count_allocs {
+        if options.is_timed {
+            eprintln!("Warning: timings include the overhead of counting allocations. `cargo time --alloc` counts them in a separate run.");
+        }
+        cmd_args.extend(["--features".to_string(), "alloc-count".to_string()]);
     }
 
+    cmd_args.push("--".to_string());
+    cmd_args.extend(options.to_args());
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
@@ -30,5 +36,9 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         .spawn()
         .unwrap();
 
-    cmd.wait().unwrap();
+    // propagate failures, e.g. mismatching answers with `--all-inputs`.
+    let status = cmd.wait().unwrap();
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
 }
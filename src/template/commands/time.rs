@@ -1,11 +1,33 @@
 use std::collections::HashSet;
+use std::process;
 
+use crate::template::history::{self, RunMetadata};
+use crate::template::regression::find_regressions;
+pub use crate::template::regression::DEFAULT_THRESHOLD;
 use crate::template::run_multi::run_multi;
+use crate::template::runner::{RunOptions, Solution};
 use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::{
+    all_days, is_configured_year, readme_benchmarks, Day, ANSI_BOLD, ANSI_RESET,
+};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+/// Controls how benchmarks are stored with `--store`.
+pub struct StoreOptions {
+    /// Store timings even if they regressed.
+    pub accept: bool,
+    /// Slowdown in percent that is tolerated before a timing counts as a regression.
+    pub threshold: f64,
+}
+
+pub fn handle(
+    day: Option<Day>,
+    run_all: bool,
+    store: Option<StoreOptions>,
+    jobs: usize,
+    options: RunOptions,
+    solutions: &[Solution],
+) {
+    let stored_timings = read_timings();
 
     let days_to_run = day.map_or_else(
         || {
@@ -21,13 +43,41 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let options = RunOptions {
+        is_timed: true,
+        ..options
+    };
+
+    let timings = run_multi(&days_to_run, true, &options, Some(jobs), solutions).unwrap();
+
+    if let Some(store) = store {
+        let regressions = find_regressions(&stored_timings, &timings, store.threshold);
+
+        if !regressions.is_empty() {
+            println!(
+                "\n{ANSI_BOLD}Timings regressed by more than {}%, or parts are no longer solved:{ANSI_RESET}",
+                store.threshold
+            );
+            regressions.iter().for_each(|r| println!("  {r}"));
 
-    if store {
+            if !store.accept {
+                eprintln!("\nRefusing to store regressed benchmarks. Pass `--accept` to store them anyway.");
+                process::exit(1);
+            }
+        }
+
+        let timings = timings.with_history(&RunMetadata::collect());
         let merged_timings = stored_timings.merge(&timings);
         merged_timings.store_file().unwrap();
 
         println!();
+
+        // the README shows the benchmarks of the configured year only.
+        if !is_configured_year() {
+            println!("Stored updated benchmarks.");
+            return;
+        }
+
         match readme_benchmarks::update(merged_timings) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
@@ -38,3 +88,23 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         }
     }
 }
+
+/// Print how the runtime of a day evolved over all stored benchmark runs.
+pub fn history(day: Day) {
+    let timings = read_timings();
+    let entries = timings.history_for(day);
+
+    if entries.is_empty() {
+        println!("No benchmark history for day {day}. Store a benchmark with `cargo time {day} --store`.");
+        return;
+    }
+
+    println!("{}", history::format_table(&entries));
+}
+
+fn read_timings() -> Timings {
+    Timings::read_from_file().unwrap_or_else(|e| {
+        eprintln!("Failed to read stored benchmarks: {e}");
+        process::exit(1);
+    })
+}
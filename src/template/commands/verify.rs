@@ -0,0 +1,84 @@
+use std::{path::Path, process};
+
+use crate::template::answers::{format_cell, Verification};
+use crate::template::run_multi::{get_path_for_bin, run_days};
+use crate::template::runner::{read_answers, Limits, RunOptions, Solution};
+use crate::template::{all_days, Day, ANSI_BOLD, ANSI_RESET};
+
+struct Row {
+    day: Day,
+    part_1: (Verification, bool),
+    part_2: (Verification, bool),
+}
+
+pub fn handle(
+    is_release: bool,
+    jobs: Option<usize>,
+    limits: Limits,
+    count_allocs: bool,
+    solutions: &[Solution],
+) {
+    // a registry that can't be read must not pass as verified.
+    let answers = read_answers();
+
+    // NOTE: only days that have been scaffolded are verified.
+    let days = all_days()
+        .filter(|day| Path::new(&get_path_for_bin(*day)).exists())
+        .collect();
+
+    let options = RunOptions {
+        limits,
+        count_allocs,
+        ..RunOptions::default()
+    };
+
+    let rows: Vec<Row> = run_days(&days, is_release, &options, jobs, solutions)
+        .into_iter()
+        .map(|(day, records)| {
+            let answer = |part: u8| {
+                records
+                    .iter()
+                    .find(|r| r.part == part)
+                    .and_then(|r| r.answer.clone())
+            };
+            let (part_1, part_2) = (answer(1), answer(2));
+
+            Row {
+                day,
+                part_1: (answers.verify(day, 1, part_1.as_deref()), part_1.is_some()),
+                part_2: (answers.verify(day, 2, part_2.as_deref()), part_2.is_some()),
+            }
+        })
+        .collect();
+
+    println!();
+    println!("{ANSI_BOLD}Summary{ANSI_RESET}");
+    println!("------");
+    println!("| Day | Part 1 | Part 2 |");
+    println!("| :---: | :---: | :---: |");
+
+    for row in &rows {
+        println!(
+            "| {} | {} | {} |",
+            row.day,
+            format_cell(&row.part_1.0, row.part_1.1),
+            format_cell(&row.part_2.0, row.part_2.1)
+        );
+    }
+
+    let mismatches = rows
+        .iter()
+        .flat_map(|row| [&row.part_1.0, &row.part_2.0])
+        .filter(|v| matches!(v, Verification::Incorrect { .. }))
+        .count();
+
+    println!();
+    if mismatches > 0 {
+        eprintln!(
+            "{ANSI_BOLD}✗ {mismatches} answer(s) do not match the stored answers.{ANSI_RESET}"
+        );
+        process::exit(1);
+    }
+
+    println!("🎄 All known answers match.");
+}
@@ -1,14 +1,19 @@
+use std::cmp::Ordering;
 use std::error::Error;
 use std::fmt::Display;
+use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 
+use crate::template::year::MAX_DAY;
+use crate::template::Year;
+
 #[cfg(feature = "today")]
 use chrono::{Datelike, FixedOffset, Utc};
 
 #[cfg(feature = "today")]
 const SERVER_UTC_OFFSET: i32 = -5;
 
-/// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// A valid day number of advent (i.e. an integer from 1 to the last day of the current year's event, see [`Year`]).
 ///
 /// # Display
 /// This value displays as a two digit number.
@@ -18,38 +23,52 @@ const SERVER_UTC_OFFSET: i32 = -5;
 /// let day = Day::new(8).unwrap();
 /// assert_eq!(day.to_string(), "08")
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Day(u8);
+///
+/// Days of solutions that live in per-year directories know their [`Year`], so that they find their data.
+/// Days compare by their number only.
+#[derive(Debug, Clone, Copy)]
+pub struct Day(u8, Option<Year>);
 
 impl Day {
-    /// Creates a [`Day`] from the provided value if it's in the valid range,
+    /// Creates a [`Day`] from the provided value if the current year's event has this day,
     /// returns [`None`] otherwise.
     pub fn new(day: u8) -> Option<Self> {
-        if day == 0 || day > 25 {
+        if day == 0 || day > Year::current_last_day() {
             return None;
         }
-        Some(Self(day))
+        Some(Self(day, None))
     }
 
     // Not part of the public API
     #[doc(hidden)]
     pub const fn __new_unchecked(day: u8) -> Self {
-        Self(day)
+        Self(day, None)
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked_in_year(day: u8, year: Year) -> Self {
+        Self(day, Some(year))
     }
 
     /// Converts the [`Day`] into an [`u8`].
-    pub fn into_inner(self) -> u8 {
+    pub const fn into_inner(self) -> u8 {
         self.0
     }
+
+    /// The year of the day, if it belongs to a solution that lives in per-year directories.
+    pub fn year(self) -> Option<Year> {
+        self.1
+    }
 }
 
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
+    /// Returns the current day if it's a day of the current year's event in december, `None` otherwise.
     pub fn today() -> Option<Self> {
         let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
         let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
+        if today.month() == 12 {
             Self::new(u8::try_from(today.day()).ok()?)
         } else {
             None
@@ -63,6 +82,32 @@ impl Display for Day {
     }
 }
 
+impl PartialEq for Day {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq(&other.0)
+    }
+}
+
+impl Eq for Day {}
+
+impl PartialOrd for Day {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Day {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl Hash for Day {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
 impl PartialEq<u8> for Day {
     fn eq(&self, other: &u8) -> bool {
         self.0.eq(other)
@@ -70,7 +115,7 @@ impl PartialEq<u8> for Day {
 }
 
 impl PartialOrd<u8> for Day {
-    fn partial_cmp(&self, other: &u8) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &u8) -> Option<Ordering> {
         self.0.partial_cmp(other)
     }
 }
@@ -94,26 +139,35 @@ impl Error for DayFromStrError {}
 
 impl Display for DayFromStrError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("expecting a day number between 1 and 25")
+        let last_day = Year::current_last_day();
+        write!(f, "expecting a day number between 1 and {last_day}")
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of the current year's event, e.g. from the 1st to the 25th.
 pub fn all_days() -> AllDays {
     AllDays::new()
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of an event, from the 1st to its last day.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Self { current: 1 }
+        Self::until(Year::current_last_day())
+    }
+
+    pub(crate) fn until(last: u8) -> Self {
+        Self {
+            current: 1,
+            last: last.min(MAX_DAY),
+        }
     }
 }
 
@@ -121,11 +175,11 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
-        let day = Day(self.current);
+        // NOTE: the iterator starts at 1 and we have verified that the value is not above the last day.
+        let day = Day(self.current, None);
         self.current += 1;
 
         Some(day)
@@ -135,15 +189,29 @@ impl Iterator for AllDays {
 /* -------------------------------------------------------------------------- */
 
 /// Creates a [`Day`] value in a const context.
+/// The day is checked against the event of `AOC_YEAR`, or of the year passed with `year = YYYY`.
 #[macro_export]
 macro_rules! day {
     ($day:expr) => {{
         const _ASSERT: () = assert!(
-            $day != 0 && $day <= 25,
+            $day != 0 && $day <= $crate::template::Year::__last_day_of(option_env!("AOC_YEAR")),
             concat!(
                 "invalid day number `",
                 $day,
-                "`, expecting a value between 1 and 25"
+                "`, expecting a day of the event configured with `AOC_YEAR`"
+            ),
+        );
+        $crate::template::Day::__new_unchecked($day)
+    }};
+    ($day:expr, year = $year:expr) => {{
+        const _ASSERT: () = assert!(
+            $day != 0 && $day <= $crate::template::Year::__new_unchecked($year).last_day(),
+            concat!(
+                "invalid day number `",
+                $day,
+                "`, expecting a day of the ",
+                $year,
+                " event"
             ),
         );
         $crate::template::Day::__new_unchecked($day)
@@ -154,37 +222,45 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{AllDays, Day};
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
-
-        assert_eq!(iter.next(), Some(Day(1)));
-        assert_eq!(iter.next(), Some(Day(2)));
-        assert_eq!(iter.next(), Some(Day(3)));
-        assert_eq!(iter.next(), Some(Day(4)));
-        assert_eq!(iter.next(), Some(Day(5)));
-        assert_eq!(iter.next(), Some(Day(6)));
-        assert_eq!(iter.next(), Some(Day(7)));
-        assert_eq!(iter.next(), Some(Day(8)));
-        assert_eq!(iter.next(), Some(Day(9)));
-        assert_eq!(iter.next(), Some(Day(10)));
-        assert_eq!(iter.next(), Some(Day(11)));
-        assert_eq!(iter.next(), Some(Day(12)));
-        assert_eq!(iter.next(), Some(Day(13)));
-        assert_eq!(iter.next(), Some(Day(14)));
-        assert_eq!(iter.next(), Some(Day(15)));
-        assert_eq!(iter.next(), Some(Day(16)));
-        assert_eq!(iter.next(), Some(Day(17)));
-        assert_eq!(iter.next(), Some(Day(18)));
-        assert_eq!(iter.next(), Some(Day(19)));
-        assert_eq!(iter.next(), Some(Day(20)));
-        assert_eq!(iter.next(), Some(Day(21)));
-        assert_eq!(iter.next(), Some(Day(22)));
-        assert_eq!(iter.next(), Some(Day(23)));
-        assert_eq!(iter.next(), Some(Day(24)));
-        assert_eq!(iter.next(), Some(Day(25)));
+        let mut iter = AllDays::until(25);
+
+        assert_eq!(iter.next(), Some(Day(1, None)));
+        assert_eq!(iter.next(), Some(Day(2, None)));
+        assert_eq!(iter.next(), Some(Day(3, None)));
+        assert_eq!(iter.next(), Some(Day(4, None)));
+        assert_eq!(iter.next(), Some(Day(5, None)));
+        assert_eq!(iter.next(), Some(Day(6, None)));
+        assert_eq!(iter.next(), Some(Day(7, None)));
+        assert_eq!(iter.next(), Some(Day(8, None)));
+        assert_eq!(iter.next(), Some(Day(9, None)));
+        assert_eq!(iter.next(), Some(Day(10, None)));
+        assert_eq!(iter.next(), Some(Day(11, None)));
+        assert_eq!(iter.next(), Some(Day(12, None)));
+        assert_eq!(iter.next(), Some(Day(13, None)));
+        assert_eq!(iter.next(), Some(Day(14, None)));
+        assert_eq!(iter.next(), Some(Day(15, None)));
+        assert_eq!(iter.next(), Some(Day(16, None)));
+        assert_eq!(iter.next(), Some(Day(17, None)));
+        assert_eq!(iter.next(), Some(Day(18, None)));
+        assert_eq!(iter.next(), Some(Day(19, None)));
+        assert_eq!(iter.next(), Some(Day(20, None)));
+        assert_eq!(iter.next(), Some(Day(21, None)));
+        assert_eq!(iter.next(), Some(Day(22, None)));
+        assert_eq!(iter.next(), Some(Day(23, None)));
+        assert_eq!(iter.next(), Some(Day(24, None)));
+        assert_eq!(iter.next(), Some(Day(25, None)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn all_days_iterator_of_short_events() {
+        let mut iter = AllDays::until(12);
+
+        assert_eq!(iter.nth(11), Some(Day(12, None)));
         assert_eq!(iter.next(), None);
     }
 }
@@ -0,0 +1,210 @@
+/// Manifest of the examples of a day, stored as `data/examples/NN.json`.
+/// The build script turns every manifest into tests, so adding an example only requires data.
+///
+/// NOTE: this module is also compiled into the build script and must only depend on `std` and `tinyjson`.
+use std::{collections::HashMap, fs, io::Error, path::Path, str::FromStr};
+use tinyjson::JsonValue;
+
+/// Expected outcome of running a part against an example.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expected {
+    /// The part returns this answer.
+    Answer(String),
+    /// The part returns `None`.
+    Unsolved,
+}
+
+/// A single example file and the parts it applies to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    /// File name relative to `data/examples`, e.g. `16-3.txt`.
+    pub file: String,
+    /// `None` if the example does not apply to part 1.
+    pub part_1: Option<Expected>,
+    /// `None` if the example does not apply to part 2.
+    pub part_2: Option<Expected>,
+}
+
+/// Represents the examples of a single day.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExampleManifest {
+    pub examples: Vec<Example>,
+}
+
+impl Example {
+    pub fn expected(&self, part: u8) -> Option<&Expected> {
+        match part {
+            1 => self.part_1.as_ref(),
+            2 => self.part_2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl ExampleManifest {
+    /// Dehydrate the manifest to a JSON file.
+    pub fn store_file(&self, path: &Path) -> Result<(), Error> {
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(path)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate a manifest from a JSON file. Returns `Ok(None)` if the day has no manifest.
+    pub fn read_from_file(path: &Path) -> Result<Option<Self>, String> {
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        fs::read_to_string(path)
+            .map_err(|e| e.to_string())
+            .and_then(ExampleManifest::try_from)
+            .map(Some)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Expected> for JsonValue {
+    fn from(value: &Expected) -> Self {
+        match value {
+            Expected::Answer(answer) => JsonValue::String(answer.clone()),
+            Expected::Unsolved => JsonValue::Null,
+        }
+    }
+}
+
+impl From<&ExampleManifest> for JsonValue {
+    fn from(value: &ExampleManifest) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "examples".into(),
+            JsonValue::Array(value.examples.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl From<&Example> for JsonValue {
+    fn from(value: &Example) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("file".into(), JsonValue::String(value.file.clone()));
+
+        // parts the example does not apply to are left out.
+        if let Some(expected) = &value.part_1 {
+            map.insert("part_1".into(), JsonValue::from(expected));
+        }
+
+        if let Some(expected) = &value.part_2 {
+            map.insert("part_2".into(), JsonValue::from(expected));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for ExampleManifest {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_examples = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("examples")
+            .ok_or("expected JSON document to have key `examples`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.examples` to be an array.")?;
+
+        Ok(ExampleManifest {
+            examples: json_examples
+                .iter()
+                .map(Example::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl TryFrom<&JsonValue> for Example {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected example to be a JSON object.")?;
+
+        let file = json
+            .get("file")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected example.file to be a string.")?;
+
+        let expected = |key: &str| match json.get(key) {
+            None => Ok(None),
+            Some(v) if v.is_null() => Ok(Some(Expected::Unsolved)),
+            Some(v) => v
+                .get::<String>()
+                .map(|answer| Some(Expected::Answer(answer.clone())))
+                .ok_or(format!("Expected example.{key} to be null or string.")),
+        };
+
+        Ok(Example {
+            file: file.clone(),
+            part_1: expected("part_1")?,
+            part_2: expected("part_2")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{Example, ExampleManifest, Expected};
+
+    #[test]
+    fn handles_json_manifests() {
+        let json = r#"{ "examples": [{ "file": "16-1.txt", "part_1": "1004", "part_2": null }, { "file": "16-3.txt", "part_1": "7036" }] }"#.to_string();
+        let manifest = ExampleManifest::try_from(json).unwrap();
+        assert_eq!(
+            manifest.examples,
+            vec![
+                Example {
+                    file: "16-1.txt".into(),
+                    part_1: Some(Expected::Answer("1004".into())),
+                    part_2: Some(Expected::Unsolved),
+                },
+                Example {
+                    file: "16-3.txt".into(),
+                    part_1: Some(Expected::Answer("7036".into())),
+                    part_2: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_manifests() {
+        let json = r#"{ "examples": [{ "file": "16-1.txt", "part_1": 1004 }] }"#.to_string();
+        ExampleManifest::try_from(json).unwrap();
+    }
+
+    #[test]
+    fn roundtrips_manifests() {
+        let manifest = ExampleManifest {
+            examples: vec![Example {
+                file: "01.txt".into(),
+                part_1: None,
+                part_2: Some(Expected::Unsolved),
+            }],
+        };
+        let json = JsonValue::from(&manifest).stringify().unwrap();
+        assert_eq!(ExampleManifest::try_from(json).unwrap(), manifest);
+    }
+}
@@ -0,0 +1,269 @@
+/// Append-only history of stored benchmark runs.
+/// Every entry is tagged with metadata about the environment it was measured in, so that runtimes can be compared over time.
+use std::{
+    collections::HashMap,
+    env,
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::stats::format_nanos;
+use crate::template::timings::Timing;
+
+/// Describes the environment a benchmark run was measured in.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RunMetadata {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// Short hash of the checked out commit, suffixed with `-dirty` if the tree had uncommitted changes.
+    pub commit: Option<String>,
+    /// Version of the compiler that built the solutions, e.g. `rustc 1.83.0 (90b35a623 2024-11-26)`.
+    pub rustc: Option<String>,
+    /// Hostname of the machine.
+    pub machine: Option<String>,
+}
+
+/// Represents the timing of a single day in a benchmark run.
+#[derive(Clone, Debug)]
+pub struct HistoryEntry {
+    pub metadata: RunMetadata,
+    pub timing: Timing,
+}
+
+impl RunMetadata {
+    /// Collect metadata about the current environment. Values that can not be determined are left empty.
+    pub fn collect() -> Self {
+        RunMetadata {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            commit: get_commit(),
+            rustc: option_env!("AOC_RUSTC_VERSION").map(String::from),
+            machine: get_machine(),
+        }
+    }
+}
+
+fn get_commit() -> Option<String> {
+    let hash = command_output("git", &["rev-parse", "--short", "HEAD"])?;
+    let is_dirty = command_output("git", &["status", "--porcelain", "--untracked-files=no"])
+        .is_some_and(|status| !status.is_empty());
+
+    Some(if is_dirty {
+        format!("{hash}-dirty")
+    } else {
+        hash
+    })
+}
+
+fn get_machine() -> Option<String> {
+    env::var("HOSTNAME")
+        .or_else(|_| env::var("COMPUTERNAME"))
+        .ok()
+        .filter(|name| !name.is_empty())
+        .or_else(|| command_output("hostname", &[]))
+}
+
+/// Run a command and return its trimmed stdout if it succeeded.
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+
+    if output.status.success() {
+        Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        None
+    }
+}
+
+/// Formats a unix timestamp as a UTC date, e.g. `2024-12-01 05:00`.
+#[allow(clippy::cast_possible_wrap)]
+fn format_timestamp(timestamp: u64) -> String {
+    let (days, seconds) = ((timestamp / 86_400) as i64, timestamp % 86_400);
+
+    // converts days since the epoch to a civil date, see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+        seconds / 3_600,
+        seconds % 3_600 / 60
+    )
+}
+
+/// Formats the history of a single day as a table, oldest run first.
+/// The last column shows the change of the total runtime relative to the previous run.
+pub fn format_table(entries: &[&HistoryEntry]) -> String {
+    let mut lines: Vec<String> = vec![
+        "| Date (UTC) | Commit | Compiler | Machine | Part 1 | Part 2 | Total | Change |".into(),
+        "| :--- | :--- | :--- | :--- | ---: | ---: | ---: | ---: |".into(),
+    ];
+
+    let mut previous: Option<f64> = None;
+
+    for entry in entries {
+        let timing = &entry.timing;
+        let total = timing.total_nanos;
+
+        let change = match previous {
+            Some(previous) if previous > 0.0 => {
+                format!("{:+.1}%", (total - previous) / previous * 100.0)
+            }
+            _ => "-".into(),
+        };
+
+        lines.push(format!(
+            "| {} | {} | {} | {} | {} | {} | {} | {} |",
+            format_timestamp(entry.metadata.timestamp),
+            entry.metadata.commit.as_deref().unwrap_or("-"),
+            entry.metadata.rustc.as_deref().unwrap_or("-"),
+            entry.metadata.machine.as_deref().unwrap_or("-"),
+            timing.part_1.as_deref().unwrap_or("-"),
+            timing.part_2.as_deref().unwrap_or("-"),
+            format_nanos(total),
+            change
+        ));
+
+        previous = Some(total);
+    }
+
+    lines.join("\n")
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryEntry> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        let metadata = &value.metadata;
+
+        let string = |v: &Option<String>| v.clone().map_or(JsonValue::Null, JsonValue::String);
+
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(metadata.timestamp as f64),
+        );
+        map.insert("commit".into(), string(&metadata.commit));
+        map.insert("rustc".into(), string(&metadata.rustc));
+        map.insert("machine".into(), string(&metadata.machine));
+        map.insert("timing".into(), JsonValue::from(&value.timing));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryEntry {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected history.timestamp to be a number.")?;
+
+        let string = |key: &str| match json.get(key) {
+            None => Ok(None),
+            Some(v) if v.is_null() => Ok(None),
+            Some(v) => v
+                .get::<String>()
+                .cloned()
+                .map(Some)
+                .ok_or(format!("Expected history.{key} to be null or string.")),
+        };
+
+        let timing = json
+            .get("timing")
+            .ok_or("Expected history.timing to be present.")?;
+
+        Ok(HistoryEntry {
+            metadata: RunMetadata {
+                timestamp: timestamp as u64,
+                commit: string("commit")?,
+                rustc: string("rustc")?,
+                machine: string("machine")?,
+            },
+            timing: Timing::try_from(timing)?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{format_table, format_timestamp, HistoryEntry, RunMetadata};
+    use crate::{day, template::timings::Timing};
+
+    fn get_mock_entry(timestamp: u64, commit: Option<&str>, total_nanos: f64) -> HistoryEntry {
+        HistoryEntry {
+            metadata: RunMetadata {
+                timestamp,
+                commit: commit.map(String::from),
+                rustc: Some("rustc 1.83.0".into()),
+                machine: None,
+            },
+            timing: Timing {
+                day: day!(1),
+                part_1: Some("1.0ms".into()),
+                part_2: None,
+                part_1_stats: None,
+                part_2_stats: None,
+                part_1_status: None,
+                part_2_status: None,
+                part_1_allocs: None,
+                part_2_allocs: None,
+                total_nanos,
+            },
+        }
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(1_733_029_200), "2024-12-01 05:00");
+        assert_eq!(format_timestamp(1_709_210_096), "2024-02-29 12:34");
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let entry = get_mock_entry(1_733_029_200, Some("abc1234"), 1_000_000_f64);
+        let parsed = HistoryEntry::try_from(&JsonValue::from(&entry)).unwrap();
+        assert_eq!(parsed.metadata, entry.metadata);
+        assert_eq!(parsed.timing.day, day!(1));
+        assert_eq!(parsed.timing.total_nanos, 1_000_000_f64);
+    }
+
+    #[test]
+    fn formats_history_with_change() {
+        let first = get_mock_entry(1_733_029_200, Some("abc1234"), 2_000_000_f64);
+        let second = get_mock_entry(1_733_115_600, None, 1_000_000_f64);
+        let table = format_table(&[&first, &second]);
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(lines.len(), 4);
+        assert_eq!(
+            lines[2],
+            "| 2024-12-01 05:00 | abc1234 | rustc 1.83.0 | - | 1.0ms | - | 2.0ms | - |"
+        );
+        assert_eq!(
+            lines[3],
+            "| 2024-12-02 05:00 | - | rustc 1.83.0 | - | 1.0ms | - | 1.0ms | -50.0% |"
+        );
+    }
+}
@@ -0,0 +1,198 @@
+/// Per-day overrides of the resource limits, e.g. a longer timeout for a day that is known to be slow.
+/// They are read from `limits.json` in the data directory of the current year, for example:
+/// `{ "data": [{ "day": "13", "timeout": 30, "part_2_timeout": 60, "day_timeout": 90, "memory_limit": 4096 }] }`
+/// Like the command-line flags they override, timeouts are in seconds and memory limits in MiB.
+use std::{collections::HashMap, fs, str::FromStr, time::Duration};
+use tinyjson::JsonValue;
+
+use crate::template::runner::Limits;
+use crate::template::{data_dir, Day};
+
+/// Stored in the data directory of the current year.
+static LIMITS_FILE_NAME: &str = "limits.json";
+
+/// Represents the limits of a single day. Limits that are not set keep the value of the command line.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LimitOverride {
+    pub day: Day,
+    pub part_timeout: Option<Duration>,
+    pub part_1_timeout: Option<Duration>,
+    pub part_2_timeout: Option<Duration>,
+    pub day_timeout: Option<Duration>,
+    /// In bytes.
+    pub memory: Option<u64>,
+}
+
+/// Represents the limits of a set of days.
+/// Can be deserialized from JSON.
+#[derive(Clone, Debug, Default)]
+pub struct LimitOverrides {
+    pub data: Vec<LimitOverride>,
+}
+
+impl LimitOverrides {
+    /// Rehydrate overrides from a JSON file. If not present, returns no overrides.
+    pub fn read_from_file() -> Result<Self, String> {
+        let path = data_dir().join(LIMITS_FILE_NAME);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(LimitOverrides::try_from)
+            .map_err(|e| format!("could not read `{}`: {e}", path.display()))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// The limits of a day: those of `limits`, replaced by the overrides of the day.
+    pub fn apply(&self, day: Day, limits: &Limits) -> Limits {
+        let Some(o) = self.data.iter().find(|o| o.day == day) else {
+            return limits.clone();
+        };
+
+        Limits {
+            part_timeout: o.part_timeout.or(limits.part_timeout),
+            part_timeouts: [
+                o.part_1_timeout.or(limits.part_timeouts[0]),
+                o.part_2_timeout.or(limits.part_timeouts[1]),
+            ],
+            day_timeout: o.day_timeout.or(limits.day_timeout),
+            memory: o.memory.or(limits.memory),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl TryFrom<String> for LimitOverrides {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(LimitOverrides {
+            data: json_data
+                .iter()
+                .map(LimitOverride::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl TryFrom<&JsonValue> for LimitOverride {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected limit to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected limit.day to be a Day struct.")?;
+
+        let number = |key: &str| -> Result<Option<f64>, String> {
+            match json.get(key) {
+                Some(v) if !v.is_null() => v
+                    .get::<f64>()
+                    .copied()
+                    .map(Some)
+                    .ok_or(format!("Expected limit.{key} to be null or a number.")),
+                _ => Ok(None),
+            }
+        };
+
+        let seconds = |key: &str| -> Result<Option<Duration>, String> {
+            number(key)?
+                .map(|seconds| {
+                    Duration::try_from_secs_f64(seconds).map_err(|e| {
+                        format!("Expected limit.{key} to be a duration in seconds: {e}.")
+                    })
+                })
+                .transpose()
+        };
+
+        let memory = number("memory_limit")?
+            .map(|mib| {
+                Some(mib)
+                    .filter(|mib| mib.fract() == 0.0 && *mib >= 0.0 && *mib <= u64::MAX as f64)
+                    .and_then(|mib| Limits::bytes_from_mib(mib as u64))
+                    .ok_or("Expected limit.memory_limit to be a number of MiB.".to_string())
+            })
+            .transpose()?;
+
+        Ok(LimitOverride {
+            day,
+            part_timeout: seconds("timeout")?,
+            part_1_timeout: seconds("part_1_timeout")?,
+            part_2_timeout: seconds("part_2_timeout")?,
+            day_timeout: seconds("day_timeout")?,
+            memory,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::time::Duration;
+
+    use super::LimitOverrides;
+    use crate::day;
+    use crate::template::runner::Limits;
+
+    #[test]
+    fn overrides_limits_of_a_day() {
+        let json = r#"{ "data": [{ "day": "13", "timeout": 30, "part_2_timeout": 60.5, "memory_limit": 4096 }] }"#;
+        let overrides = LimitOverrides::try_from(json.to_string()).unwrap();
+
+        let limits = Limits {
+            part_timeout: Some(Duration::from_secs(5)),
+            day_timeout: Some(Duration::from_secs(10)),
+            ..Limits::default()
+        };
+
+        let day_13 = overrides.apply(day!(13), &limits);
+        assert_eq!(day_13.part_timeout_of(1), Some(Duration::from_secs(30)));
+        assert_eq!(
+            day_13.part_timeout_of(2),
+            Some(Duration::from_secs_f64(60.5))
+        );
+        assert_eq!(day_13.day_timeout, Some(Duration::from_secs(10)));
+        assert_eq!(day_13.memory, Some(4096 * 1024 * 1024));
+
+        let day_1 = overrides.apply(day!(1), &limits);
+        assert_eq!(day_1.part_timeout_of(2), Some(Duration::from_secs(5)));
+        assert_eq!(day_1.memory, None);
+    }
+
+    #[test]
+    fn rejects_invalid_limits() {
+        for limit in [
+            r#""timeout": -1"#,
+            r#""timeout": "30""#,
+            r#""memory_limit": 1.5"#,
+            r#""memory_limit": 1e14"#,
+        ] {
+            let json = format!(r#"{{ "data": [{{ "day": "13", {limit} }}] }}"#);
+            assert!(LimitOverrides::try_from(json).is_err(), "{limit}");
+        }
+    }
+}
@@ -0,0 +1,320 @@
+//! Converts puzzle pages of adventofcode.com to markdown, in the format that aoc-cli writes to `data/puzzles`.
+//! Only the handful of elements that puzzle pages use are supported.
+
+/// Convert the `<main>` element of a page, or the whole document if it has none.
+pub fn html_to_markdown(html: &str) -> String {
+    let content = match (html.find("<main>"), html.rfind("</main>")) {
+        (Some(start), Some(end)) if start < end => &html[start + "<main>".len()..end],
+        _ => html,
+    };
+
+    let mut converter = Converter::default();
+    let mut rest = content;
+
+    while let Some(start) = rest.find('<') {
+        converter.text(&rest[..start]);
+        rest = &rest[start..];
+
+        // comments may contain `>`.
+        if rest.starts_with("<!--") {
+            rest = rest.find("-->").map_or("", |end| &rest[end + 3..]);
+            continue;
+        }
+
+        let Some(end) = rest.find('>') else {
+            break;
+        };
+        converter.tag(&rest[1..end]);
+        rest = &rest[end + 1..];
+    }
+
+    converter.text(rest);
+    converter.finish()
+}
+
+#[derive(Default)]
+struct Converter {
+    output: String,
+    /// Text of the current paragraph, heading or list item.
+    inline: String,
+    /// Raw text of the current `<pre>` block.
+    block: Option<String>,
+    /// Targets of the open links.
+    links: Vec<String>,
+    /// Depth of elements whose content is dropped, e.g. `<script>` or `<form>`.
+    skipped: usize,
+}
+
+impl Converter {
+    fn text(&mut self, text: &str) {
+        if self.skipped > 0 || text.is_empty() {
+            return;
+        }
+
+        let text = decode_entities(text);
+
+        if let Some(block) = &mut self.block {
+            block.push_str(&text);
+            return;
+        }
+
+        for c in text.chars() {
+            if !c.is_whitespace() {
+                self.inline.push(c);
+            } else if !self.inline.is_empty() && !self.inline.ends_with([' ', '\n']) {
+                self.inline.push(' ');
+            }
+        }
+    }
+
+    fn tag(&mut self, tag: &str) {
+        let is_closing = tag.starts_with('/');
+        let tag = tag.trim_start_matches('/');
+        let name = tag
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        if matches!(name.as_str(), "script" | "style" | "form") {
+            if is_closing {
+                self.skipped = self.skipped.saturating_sub(1);
+            } else {
+                self.skipped += 1;
+            }
+            return;
+        }
+
+        if self.skipped > 0 {
+            return;
+        }
+
+        // highlights within examples are not part of their text.
+        if self.block.is_some() && name != "pre" {
+            return;
+        }
+
+        match (name.as_str(), is_closing) {
+            ("p" | "article" | "div", _) | ("ul", false) => self.paragraph(),
+            ("ul", true) => {
+                self.list_item();
+                if !self.output.ends_with("\n\n") {
+                    self.output.push('\n');
+                }
+            }
+            ("h2", false) => self.paragraph(),
+            ("h2", true) => self.heading(),
+            ("li", false) => self.paragraph(),
+            ("li", true) => self.list_item(),
+            ("br", _) => self.inline.push('\n'),
+            ("pre", false) => {
+                self.paragraph();
+                self.block = Some(String::new());
+            }
+            ("pre", true) => self.code_block(),
+            ("code", _) => self.mark("`", is_closing),
+            ("em", _) => self.mark("*", is_closing),
+            ("a", false) => {
+                let href = attribute(tag, "href").unwrap_or_default();
+                self.inline.push('[');
+                self.links.push(href);
+            }
+            ("a", true) => {
+                if let Some(href) = self.links.pop() {
+                    self.inline.push_str(&format!("]({href})"));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Insert a marker for inline formatting. Closing markers are kept next to the text they emphasize.
+    fn mark(&mut self, marker: &str, is_closing: bool) {
+        if is_closing && self.inline.ends_with(' ') {
+            self.inline.pop();
+            self.inline.push_str(marker);
+            self.inline.push(' ');
+        } else {
+            self.inline.push_str(marker);
+        }
+    }
+
+    fn take_inline(&mut self) -> String {
+        self.links.clear();
+        let text = self.inline.trim().to_string();
+        self.inline.clear();
+        text
+    }
+
+    fn paragraph(&mut self) {
+        let text = self.take_inline();
+        if !text.is_empty() {
+            self.output.push_str(&text);
+            self.output.push_str("\n\n");
+        }
+    }
+
+    fn heading(&mut self) {
+        let text = self.take_inline();
+        // a line starting with `---` would be a horizontal rule.
+        let text = if text.starts_with('-') {
+            format!("\\{text}")
+        } else {
+            text
+        };
+        self.output.push_str(&format!("{text}\n----------\n\n"));
+    }
+
+    fn list_item(&mut self) {
+        let text = self.take_inline();
+        if !text.is_empty() {
+            self.output.push_str(&format!("* {text}\n"));
+        }
+    }
+
+    fn code_block(&mut self) {
+        let Some(mut block) = self.block.take() else {
+            return;
+        };
+        if !block.ends_with('\n') {
+            block.push('\n');
+        }
+        self.output.push_str(&format!("```\n{block}```\n\n"));
+    }
+
+    fn finish(mut self) -> String {
+        self.paragraph();
+        let output = self.output.trim_end();
+        if output.is_empty() {
+            String::new()
+        } else {
+            format!("{output}\n")
+        }
+    }
+}
+
+/// Value of an attribute of a tag, e.g. the `href` of `a href="/2024/day/1"`.
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let length = tag[start..].find('"')?;
+    Some(decode_entities(&tag[start..start + length]))
+}
+
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest.find(';').and_then(|end| {
+            let c = match &rest[1..end] {
+                "lt" => '<',
+                "gt" => '>',
+                "amp" => '&',
+                "quot" => '"',
+                "apos" => '\'',
+                "nbsp" => ' ',
+                code => {
+                    let number = match code.strip_prefix("#x") {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => code.strip_prefix('#')?.parse().ok()?,
+                    };
+                    char::from_u32(number)?
+                }
+            };
+            Some((c, end))
+        });
+
+        match entity {
+            Some((c, end)) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{decode_entities, html_to_markdown};
+    use crate::template::puzzle::{extract_code_blocks, find_example_answers, find_puzzle_answers};
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html><head><title>Day 1 - Advent of Code 2024</title><script>var x = "<p>";</script></head>
+<body><header><h1><a href="/">Advent of Code</a></h1></header>
+<main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2><p>The lists are <em>not</em> the same.</p>
+<p>For example:</p>
+<pre><code>3   4
+4   <em>3</em>
+</code></pre>
+<ul>
+<li>The smallest pair is <code>1</code> and <code>3</code>.</li>
+<li>See <a href="/2024/day/1/input" target="_blank">your input</a>.</li>
+</ul>
+<p>In total, the distance is <code><em>11</em></code> &lt;-- that&#39;s it.</p>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<!-- a <comment> -->
+<form method="post" action="1/answer"><input type="text" name="answer"/></form>
+</main>
+</body></html>"#;
+
+    #[test]
+    fn converts_puzzle_pages() {
+        assert_eq!(
+            html_to_markdown(PAGE),
+            "\\--- Day 1: Historian Hysteria ---
+----------
+
+The lists are *not* the same.
+
+For example:
+
+```
+3   4
+4   3
+```
+
+* The smallest pair is `1` and `3`.
+* See [your input](/2024/day/1/input).
+
+In total, the distance is `*11*` <-- that's it.
+
+Your puzzle answer was `1234`.
+"
+        );
+    }
+
+    #[test]
+    fn converted_pages_can_be_parsed() {
+        let markdown = html_to_markdown(PAGE);
+
+        assert_eq!(extract_code_blocks(&markdown)[0].content, "3   4\n4   3\n");
+        assert_eq!(find_example_answers(&markdown)[0].answer, "11");
+        assert_eq!(find_puzzle_answers(&markdown), vec!["1234"]);
+    }
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(
+            decode_entities("&lt;a&gt; &amp; &#39;b&#x27; &unknown; &"),
+            "<a> & 'b' &unknown; &"
+        );
+    }
+}
@@ -1,25 +1,44 @@
-use std::{env, fs};
+use std::{env, fs, path::PathBuf};
 
+pub mod alloc;
 pub mod aoc_cli;
+pub mod aoc_client;
 pub mod commands;
+pub mod examples;
 pub mod runner;
 
 pub use day::*;
+pub use year::*;
 
+mod answers;
+mod checksums;
 mod day;
+mod history;
+mod limits;
+mod markdown;
+mod puzzle;
 mod readme_benchmarks;
+mod records;
+mod regression;
 mod run_multi;
+mod stats;
+mod submissions;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
 /// Helper function that reads a text file to a string.
+/// Files are read from the data directory of the day's year, e.g. `data/2023/examples/01.txt`.
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd
+        .join(data_dir_for(Some(day)))
+        .join(folder)
+        .join(format!("{day}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
@@ -29,40 +48,114 @@ pub fn read_file(folder: &str, day: Day) -> String {
 pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
+        .join(data_dir_for(Some(day)))
         .join(folder)
         .join(format!("{day}-{part}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
+/// Helper function that lists the named inputs of a day in `data/inputs/NN/`, sorted by name.
+/// E.g. `data/inputs/07/alice.txt` is the input `alice`.
+#[must_use]
+pub fn list_inputs(day: Day) -> Vec<(String, PathBuf)> {
+    let dir = env::current_dir()
+        .unwrap()
+        .join(data_dir_for(Some(day)))
+        .join("inputs")
+        .join(day.to_string());
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut inputs: Vec<(String, PathBuf)> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "txt" {
+                return None;
+            }
+            Some((path.file_stem()?.to_str()?.to_string(), path))
+        })
+        .collect();
+
+    inputs.sort();
+    inputs
+}
+
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// Solutions of years other than `AOC_YEAR` pass their year, e.g. `solution!(5, year = 2023)`.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($day:expr $(, year = $year:literal)?) => {
+        $crate::solution!(@impl $day, [$($year)?], [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, 1 $(, year = $year:literal)?) => {
+        $crate::solution!(@impl $day, [$($year)?], [part_one, 1]);
+    };
+    ($day:expr, 2 $(, year = $year:literal)?) => {
+        $crate::solution!(@impl $day, [$($year)?], [part_two, 2]);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+
+    (@day $day:expr, []) => {
+        $crate::day!($day)
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    (@day $day:expr, [$year:literal]) => {
+        $crate::template::Day::__new_unchecked_in_year(
+            $crate::day!($day, year = $year).into_inner(),
+            $crate::template::Year::__new_unchecked($year),
+        )
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@impl $day:expr, [$($year:literal)?], $( [$func:expr, $part:expr] )*) => {
         /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+        const DAY: $crate::template::Day = $crate::solution!(@day $day, [$($year)?]);
 
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
+        #[cfg(all(feature = "alloc-count", not(feature = "dhat-heap")))]
+        #[global_allocator]
+        static ALLOC: $crate::template::alloc::CountingAllocator =
+            $crate::template::alloc::CountingAllocator;
+
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+
+            if let Some(year) = DAY.year() {
+                $crate::template::Year::select(year);
+            }
+
+            let options = RunOptions::from_args();
+
+            if options.all_inputs {
+                run_all_inputs(DAY, SOLUTION.run, &options);
+                return;
+            }
+
+            let input = options.read_input(DAY);
+            $( run_part($func, &input, DAY, $part, &options); )*
         }
+
+        /// Tests generated from the example manifest in `data/examples/NN.json`.
+        #[cfg(test)]
+        #[allow(unused_imports)]
+        mod example_tests {
+            use super::*;
+
+            include!(concat!(env!("OUT_DIR"), "/examples/", $($year, "/",)? $day, ".rs"));
+        }
+
+        /// Entry of this day in the in-process solution registry.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::runner::Solution = $crate::template::runner::Solution {
+            day: DAY,
+            run: |input: &str, options: &$crate::template::runner::RunOptions| {
+                vec![$( $crate::template::runner::run_part($func, input, DAY, $part, options) ),*]
+            },
+        };
     };
 }
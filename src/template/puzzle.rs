@@ -0,0 +1,319 @@
+//! Extracts code blocks from puzzle descriptions downloaded with `cargo download`.
+//! Puzzle examples are code blocks, so they can be written to `data/examples` without copy and paste.
+
+/// A fenced code block of a puzzle description.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodeBlock {
+    /// Content of the block, ending with a newline.
+    pub content: String,
+    /// Whether the paragraph before the block mentions an example. Such blocks are likely puzzle examples.
+    pub is_suggested: bool,
+    /// Lines of the description the block starts on, one per occurrence.
+    pub positions: Vec<usize>,
+}
+
+/// An answer that the puzzle description states for an example.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExampleAnswer {
+    pub part: u8,
+    pub answer: String,
+    /// Line of the description the answer is stated on, used to find the example it belongs to.
+    pub line: usize,
+}
+
+impl CodeBlock {
+    /// First lines of the block, truncated to `max_lines`.
+    pub fn preview(&self, max_lines: usize) -> String {
+        let lines: Vec<&str> = self.content.lines().collect();
+        let mut preview = lines
+            .iter()
+            .take(max_lines)
+            .map(|line| format!("    {line}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        if lines.len() > max_lines {
+            preview.push_str(&format!(
+                "\n    ... ({} more lines)",
+                lines.len() - max_lines
+            ));
+        }
+
+        preview
+    }
+}
+
+/// Collect the fenced code blocks of a markdown document in order.
+/// Repeated blocks, e.g. an example that is shown again with highlights, are only returned once.
+pub fn extract_code_blocks(markdown: &str) -> Vec<CodeBlock> {
+    let mut blocks: Vec<CodeBlock> = vec![];
+    let mut paragraph = String::new();
+    let mut open_block: Option<(&str, usize, Vec<&str>)> = None;
+
+    for (line_number, line) in markdown.lines().enumerate() {
+        let trimmed = line.trim_start();
+
+        if let Some((fence, start, lines)) = &mut open_block {
+            if trimmed.starts_with(*fence) {
+                let content = lines.iter().map(|line| format!("{line}\n")).collect();
+                let is_suggested = paragraph.to_lowercase().contains("example");
+
+                match blocks.iter_mut().find(|block| block.content == content) {
+                    Some(block) => {
+                        block.is_suggested |= is_suggested;
+                        block.positions.push(*start);
+                    }
+                    None => blocks.push(CodeBlock {
+                        content,
+                        is_suggested,
+                        positions: vec![*start],
+                    }),
+                }
+
+                open_block = None;
+                paragraph.clear();
+            } else {
+                lines.push(line);
+            }
+        } else if let Some(fence) = ["```", "~~~"].into_iter().find(|f| trimmed.starts_with(f)) {
+            open_block = Some((fence, line_number, vec![]));
+        } else if trimmed.is_empty() {
+            // remember the last paragraph, it usually introduces the next block.
+            if !paragraph.is_empty() {
+                paragraph.push('\n');
+            }
+        } else {
+            if paragraph.ends_with('\n') {
+                paragraph.clear();
+            }
+            paragraph.push_str(trimmed);
+            paragraph.push(' ');
+        }
+    }
+
+    blocks
+}
+
+/// Find the answers the description states for its examples.
+/// Puzzles usually end each part by emphasizing the example answer in a code span, so the last one of each part is picked.
+pub fn find_example_answers(markdown: &str) -> Vec<ExampleAnswer> {
+    let mut answers: Vec<ExampleAnswer> = vec![];
+    let mut part = 1;
+    let mut is_in_block = false;
+
+    for (line_number, line) in markdown.lines().enumerate() {
+        let trimmed = line.trim_start();
+
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            is_in_block = !is_in_block;
+            continue;
+        }
+
+        // our own answers are stated after each part once it is solved.
+        if is_in_block || trimmed.starts_with("Your puzzle answer was") {
+            continue;
+        }
+
+        if trimmed.contains("--- Part Two ---") {
+            part = 2;
+            continue;
+        }
+
+        if let Some(answer) = emphasized_code_spans(trimmed).pop() {
+            answers.retain(|a| a.part != part);
+            answers.push(ExampleAnswer {
+                part,
+                answer,
+                line: line_number,
+            });
+        }
+    }
+
+    answers
+}
+
+/// Find our own accepted answers. Once a part is solved, its page states `Your puzzle answer was ...`, part 1 first.
+pub fn find_puzzle_answers(markdown: &str) -> Vec<String> {
+    markdown
+        .lines()
+        .filter_map(|line| line.trim_start().strip_prefix("Your puzzle answer was"))
+        .filter_map(|rest| {
+            let rest = rest.trim();
+            let answer = match rest.split('`').nth(1) {
+                Some(code) => code.trim_matches('*'),
+                None => rest.trim_end_matches('.').trim_matches('*'),
+            };
+            (!answer.is_empty()).then(|| answer.to_string())
+        })
+        .take(2)
+        .collect()
+}
+
+/// Collect the emphasized code spans of a line, e.g. `*`11`*` or `` `*11*` ``.
+fn emphasized_code_spans(line: &str) -> Vec<String> {
+    let mut spans: Vec<String> = vec![];
+    let mut rest = line;
+
+    while let Some(start) = rest.find('`') {
+        let Some(length) = rest[start + 1..].find('`') else {
+            break;
+        };
+        let end = start + 1 + length;
+
+        let code = &rest[start + 1..end];
+        let inner = code.trim_matches('*');
+
+        let is_wrapped = rest[..start].ends_with('*') && rest[end + 1..].starts_with('*');
+        let is_emphasized = is_wrapped || (code.starts_with('*') && code.ends_with('*'));
+
+        if is_emphasized && !inner.is_empty() {
+            spans.push(inner.to_string());
+        }
+
+        rest = &rest[end + 1..];
+    }
+
+    spans
+}
+
+/// Parse a selection of blocks like `1,3`, numbered from 1. Returns indices into the list of blocks.
+pub fn parse_selection(selection: &str, count: usize) -> Result<Vec<usize>, String> {
+    selection
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| match s.parse::<usize>() {
+            Ok(number) if (1..=count).contains(&number) => Ok(number - 1),
+            _ => Err(format!("`{s}` is not a block between 1 and {count}.")),
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        emphasized_code_spans, extract_code_blocks, find_example_answers, find_puzzle_answers,
+        parse_selection, CodeBlock, ExampleAnswer,
+    };
+
+    const PUZZLE: &str = "\\--- Day 1: Historian Hysteria ---
+----------
+
+The lists are `3` and `4` apart.
+
+For example:
+
+```
+3   4
+4   3
+```
+
+Some unrelated text.
+
+```
+not an example
+```
+
+Again, with the pairs highlighted:
+
+```
+3   4
+4   3
+```
+
+In the example above, the total distance is `1` + `1` = *`2`*!
+
+Your puzzle answer was `1234`.
+
+\\--- Part Two ---
+----------
+
+The similarity score is `*12*` and `*14*`, in total *`26`*.
+";
+
+    #[test]
+    fn extracts_code_blocks() {
+        assert_eq!(
+            extract_code_blocks(PUZZLE),
+            vec![
+                CodeBlock {
+                    content: "3   4\n4   3\n".into(),
+                    is_suggested: true,
+                    positions: vec![7, 20],
+                },
+                CodeBlock {
+                    content: "not an example\n".into(),
+                    is_suggested: false,
+                    positions: vec![14],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_documents_without_blocks() {
+        assert_eq!(extract_code_blocks("no `blocks` here"), vec![]);
+    }
+
+    #[test]
+    fn previews_blocks() {
+        let block = CodeBlock {
+            content: "a\nb\nc\n".into(),
+            is_suggested: false,
+            positions: vec![0],
+        };
+        assert_eq!(block.preview(2), "    a\n    b\n    ... (1 more lines)");
+        assert_eq!(block.preview(3), "    a\n    b\n    c");
+    }
+
+    #[test]
+    fn finds_example_answers() {
+        assert_eq!(
+            find_example_answers(PUZZLE),
+            vec![
+                ExampleAnswer {
+                    part: 1,
+                    answer: "2".into(),
+                    line: 25,
+                },
+                ExampleAnswer {
+                    part: 2,
+                    answer: "26".into(),
+                    line: 32,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_puzzle_answers() {
+        assert_eq!(find_puzzle_answers(PUZZLE), vec!["1234"]);
+        assert_eq!(
+            find_puzzle_answers("Your puzzle answer was `12`.\n\nYour puzzle answer was *`ABC`*."),
+            vec!["12", "ABC"]
+        );
+        assert_eq!(
+            find_puzzle_answers("Your puzzle answer was 12."),
+            vec!["12"]
+        );
+    }
+
+    #[test]
+    fn finds_emphasized_code_spans() {
+        assert_eq!(
+            emphasized_code_spans("`1` is *`2`*, `**3**` and **`4`** but not `*`"),
+            vec!["2", "3", "4"]
+        );
+    }
+
+    #[test]
+    fn parses_selections() {
+        assert_eq!(parse_selection("1, 3", 3), Ok(vec![0, 2]));
+        assert_eq!(parse_selection("", 3), Ok(vec![]));
+        assert!(parse_selection("4", 3).is_err());
+        assert!(parse_selection("0", 3).is_err());
+        assert!(parse_selection("a", 3).is_err());
+    }
+}
@@ -2,8 +2,11 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
+use crate::template::alloc::AllocStats;
+use crate::template::records::PartStatus;
+use crate::template::stats::BenchStats;
 use crate::template::timings::Timings;
-use crate::template::Day;
+use crate::template::{bin_path, Day};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -27,7 +30,7 @@ pub struct TablePosition {
 
 #[must_use]
 pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+    format!("./{}", bin_path(day))
 }
 
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
@@ -55,22 +58,50 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
 fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
     let header = format!("{prefix} Benchmarks");
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    // allocation columns are only shown if allocations were counted.
+    let has_allocs = timings
+        .data
+        .iter()
+        .any(|t| t.part_1_allocs.is_some() || t.part_2_allocs.is_some());
+
+    let mut lines: Vec<String> = vec![MARKER.into(), header, String::new()];
+
+    if has_allocs {
+        lines.push("| Day | Part 1 | Part 2 | Part 1 Memory | Part 2 Memory |".into());
+        lines.push("| :---: | :---: | :---: | :---: | :---: |".into());
+    } else {
+        lines.push("| Day | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---:  |".into());
+    }
 
     for timing in timings.data {
         let path = get_path_for_bin(timing.day);
+
+        let alloc_columns = if has_allocs {
+            format!(
+                " `{}` | `{}` |",
+                format_allocs(timing.part_1_allocs.as_ref()),
+                format_allocs(timing.part_2_allocs.as_ref())
+            )
+        } else {
+            String::new()
+        };
+
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [Day {}]({}) | `{}` | `{}` |{}",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            format_part(
+                timing.part_1,
+                timing.part_1_stats.as_ref(),
+                timing.part_1_status
+            ),
+            format_part(
+                timing.part_2,
+                timing.part_2_stats.as_ref(),
+                timing.part_2_status
+            ),
+            alloc_columns
         ));
     }
 
@@ -81,6 +112,29 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
     lines.join("\n")
 }
 
+/// Prefer the median and its spread if the timing has statistics.
+/// Parts that were aborted show why.
+fn format_part(
+    duration: Option<String>,
+    stats: Option<&BenchStats>,
+    status: Option<PartStatus>,
+) -> String {
+    match (duration, stats, status) {
+        (Some(_), Some(stats), _) => stats.format_median(),
+        (Some(duration), None, _) => duration,
+        (
+            None,
+            _,
+            Some(status @ (PartStatus::TimedOut | PartStatus::OutOfMemory | PartStatus::Crashed)),
+        ) => status.to_string(),
+        (None, _, _) => "-".into(),
+    }
+}
+
+fn format_allocs(allocs: Option<&AllocStats>) -> String {
+    allocs.map_or_else(|| "-".into(), AllocStats::format_short)
+}
+
 fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
     let positions = locate_table(s)?;
     let table = construct_table("##", timings, total_millis);
@@ -99,8 +153,13 @@ pub fn update(timings: Timings) -> Result<(), Error> {
 
 #[cfg(feature = "test_lib")]
 mod tests {
+    use std::time::Duration;
+
     use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use crate::{
+        day, template::alloc::AllocStats, template::records::PartStatus,
+        template::stats::BenchStats, template::timings::Timing, template::timings::Timings,
+    };
 
     fn get_mock_timings() -> Timings {
         Timings {
@@ -109,21 +168,40 @@ mod tests {
                     day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    part_1_allocs: None,
+                    part_2_allocs: None,
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    part_1_allocs: None,
+                    part_2_allocs: None,
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    part_1_allocs: None,
+                    part_2_allocs: None,
                     total_nanos: 9e+10,
                 },
             ],
+            history: vec![],
         }
     }
 
@@ -180,4 +258,62 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn formats_median_with_spread() {
+        let mut timings = get_mock_timings();
+        timings.data[0].part_1_stats = BenchStats::from_samples(&[
+            Duration::from_millis(9),
+            Duration::from_millis(10),
+            Duration::from_millis(11),
+        ]);
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert_eq!(
+            s.contains("| [Day 1](./src/bin/01.rs) | `10.0ms ± 1.0ms` | `20ms` |"),
+            true
+        );
+    }
+
+    #[test]
+    fn formats_aborted_parts() {
+        let mut timings = get_mock_timings();
+        timings.data[1].part_2 = None;
+        timings.data[1].part_2_status = Some(PartStatus::TimedOut);
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert_eq!(
+            s.contains("| [Day 2](./src/bin/02.rs) | `30ms` | `timed out` |"),
+            true
+        );
+    }
+
+    #[test]
+    fn formats_alloc_columns() {
+        let mut timings = get_mock_timings();
+        timings.data[0].part_1_allocs = Some(AllocStats {
+            allocations: 12,
+            bytes: 4096,
+            peak: 1536,
+        });
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert_eq!(
+            s.contains("| Day | Part 1 | Part 2 | Part 1 Memory | Part 2 Memory |"),
+            true
+        );
+        assert_eq!(
+            s.contains(
+                "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` | `12 allocs, 1.5 KiB peak` | `-` |"
+            ),
+            true
+        );
+        assert_eq!(
+            s.contains("| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` | `-` | `-` |"),
+            true
+        );
+    }
 }
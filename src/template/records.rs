@@ -0,0 +1,303 @@
+/// Machine-readable protocol between solution binaries and the runner.
+/// When invoked with `--json`, a solution emits one JSON record per part on stdout.
+use std::{collections::HashMap, fmt::Display, str::FromStr, time::Duration};
+use tinyjson::JsonValue;
+
+use crate::template::alloc::AllocStats;
+use crate::template::stats::BenchStats;
+
+/// Outcome of running a single solution part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    /// The part returned an answer.
+    Solved,
+    /// The part returned `None`.
+    Unsolved,
+    /// The part exceeded its wall-clock limit and was killed.
+    TimedOut,
+    /// The part exceeded its memory limit.
+    OutOfMemory,
+    /// The solution exited abnormally while running the part, e.g. because it panicked.
+    Crashed,
+}
+
+/// Represents the result of running a single solution part.
+#[derive(Clone, Debug)]
+pub struct PartRecord {
+    pub part: u8,
+    pub status: PartStatus,
+    pub answer: Option<String>,
+    /// Human-readable duration, e.g. `74.1ns`.
+    pub duration: String,
+    pub nanos: f64,
+    pub samples: u128,
+    /// Benchmark statistics, if the part was benched.
+    pub stats: Option<BenchStats>,
+    /// Allocations of a single run, if the solution counts allocations.
+    pub allocs: Option<AllocStats>,
+}
+
+impl PartRecord {
+    pub fn new(
+        part: u8,
+        answer: Option<String>,
+        duration: &Duration,
+        samples: u128,
+        stats: Option<BenchStats>,
+    ) -> Self {
+        PartRecord {
+            part,
+            status: if answer.is_some() {
+                PartStatus::Solved
+            } else {
+                PartStatus::Unsolved
+            },
+            answer,
+            duration: format!("{duration:.1?}"),
+            #[allow(clippy::cast_precision_loss)]
+            nanos: duration.as_nanos() as f64,
+            samples,
+            stats,
+            allocs: None,
+        }
+    }
+
+    /// Create a record for a part that was aborted after running for `duration`.
+    pub fn aborted(part: u8, status: PartStatus, duration: &Duration) -> Self {
+        PartRecord {
+            part,
+            status,
+            answer: None,
+            duration: format!("{duration:.1?}"),
+            #[allow(clippy::cast_precision_loss)]
+            nanos: duration.as_nanos() as f64,
+            samples: 0,
+            stats: None,
+            allocs: None,
+        }
+    }
+
+    /// Serialize the record to a single line of JSON.
+    pub fn to_json_line(&self) -> String {
+        JsonValue::from(self)
+            .stringify()
+            .expect("records only contain finite numbers")
+    }
+}
+
+impl Display for PartStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartStatus::Solved => write!(f, "solved"),
+            PartStatus::Unsolved => write!(f, "unsolved"),
+            PartStatus::TimedOut => write!(f, "timed out"),
+            PartStatus::OutOfMemory => write!(f, "out of memory"),
+            PartStatus::Crashed => write!(f, "crashed"),
+        }
+    }
+}
+
+impl FromStr for PartStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "solved" => Ok(PartStatus::Solved),
+            "unsolved" => Ok(PartStatus::Unsolved),
+            "timed out" => Ok(PartStatus::TimedOut),
+            "out of memory" => Ok(PartStatus::OutOfMemory),
+            "crashed" => Ok(PartStatus::Crashed),
+            s => Err(format!("unknown part status `{s}`.")),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartRecord> for JsonValue {
+    fn from(value: &PartRecord) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("status".into(), JsonValue::String(value.status.to_string()));
+        map.insert(
+            "answer".into(),
+            value
+                .answer
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert("duration".into(), JsonValue::String(value.duration.clone()));
+        map.insert("nanos".into(), JsonValue::Number(value.nanos));
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+
+        map.insert(
+            "stats".into(),
+            value
+                .stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "allocs".into(),
+            value
+                .allocs
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&str> for PartRecord {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(value).or(Err("not valid JSON."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected record to be a JSON object.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .filter(|v| **v == 1.0 || **v == 2.0)
+            .ok_or("Expected record.part to be 1 or 2.")?;
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected record.status to be a string.")?
+            .parse()?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected record.answer to be null or string.")?;
+
+        let duration = json
+            .get("duration")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected record.duration to be a string.")?;
+
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected record.nanos to be a number.")?;
+
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected record.samples to be a number.")?;
+
+        let stats = match json.get("stats") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(BenchStats::try_from(v)?),
+        };
+
+        let allocs = match json.get("allocs") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(AllocStats::try_from(v)?),
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(PartRecord {
+            part: *part as u8,
+            status,
+            answer: answer.cloned(),
+            duration: duration.clone(),
+            nanos,
+            samples: samples as u128,
+            stats,
+            allocs,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{PartRecord, PartStatus};
+    use crate::template::alloc::AllocStats;
+    use crate::template::stats::BenchStats;
+
+    #[test]
+    fn roundtrips_records() {
+        let record = PartRecord::new(
+            2,
+            Some("@ ( ) ms\n1,2".into()),
+            &Duration::from_nanos(74_130),
+            100_000,
+            None,
+        );
+        let parsed = PartRecord::try_from(record.to_json_line().as_str()).unwrap();
+        assert_eq!(parsed.part, 2);
+        assert_eq!(parsed.status, PartStatus::Solved);
+        assert_eq!(parsed.answer.unwrap(), "@ ( ) ms\n1,2");
+        assert_eq!(parsed.duration, "74.1µs");
+        assert_eq!(parsed.nanos, 74_130_f64);
+        assert_eq!(parsed.samples, 100_000);
+        assert_eq!(parsed.stats, None);
+    }
+
+    #[test]
+    fn roundtrips_bench_stats() {
+        let stats = BenchStats::from_samples(&[Duration::from_nanos(10), Duration::from_nanos(20)]);
+        let record = PartRecord::new(1, Some("1".into()), &Duration::from_nanos(15), 2, stats);
+        let parsed = PartRecord::try_from(record.to_json_line().as_str()).unwrap();
+        assert_eq!(parsed.stats, record.stats);
+    }
+
+    #[test]
+    fn roundtrips_alloc_stats() {
+        let record = PartRecord {
+            allocs: Some(AllocStats {
+                allocations: 3,
+                bytes: 1024,
+                peak: 512,
+            }),
+            ..PartRecord::new(1, Some("1".into()), &Duration::from_nanos(15), 1, None)
+        };
+        let parsed = PartRecord::try_from(record.to_json_line().as_str()).unwrap();
+        assert_eq!(parsed.allocs, record.allocs);
+    }
+
+    #[test]
+    fn serializes_to_a_single_line() {
+        let record = PartRecord::new(1, Some("a\nb".into()), &Duration::from_millis(1), 1, None);
+        assert_eq!(record.to_json_line().lines().count(), 1);
+    }
+
+    #[test]
+    fn handles_unsolved_parts() {
+        let record = PartRecord::new(1, None, &Duration::from_millis(1), 1, None);
+        let parsed = PartRecord::try_from(record.to_json_line().as_str()).unwrap();
+        assert_eq!(parsed.status, PartStatus::Unsolved);
+        assert!(parsed.answer.is_none());
+    }
+
+    #[test]
+    fn roundtrips_aborted_parts() {
+        let record = PartRecord::aborted(2, PartStatus::TimedOut, &Duration::from_secs(10));
+        let parsed = PartRecord::try_from(record.to_json_line().as_str()).unwrap();
+        assert_eq!(parsed.status, PartStatus::TimedOut);
+        assert_eq!(parsed.duration, "10.0s");
+        assert!(parsed.answer.is_none());
+    }
+
+    #[test]
+    fn rejects_other_lines() {
+        assert!(PartRecord::try_from("Part 1: 42 (1ms)").is_err());
+        assert!(PartRecord::try_from(r#"{ "part": 3 }"#).is_err());
+    }
+}
@@ -0,0 +1,300 @@
+/// Detects performance regressions between stored and newly measured timings.
+use std::fmt::Display;
+
+use crate::template::records::PartStatus;
+use crate::template::stats::{format_nanos, BenchStats};
+use crate::template::timings::{Timing, Timings};
+use crate::template::Day;
+
+/// Default slowdown, in percent, that is tolerated before a timing counts as a regression.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// A slowdown is only meaningful if it exceeds this many standard deviations of the combined measurement noise.
+const NOISE_SIGMAS: f64 = 2.0;
+
+/// Represents a timing that got slower than tolerated, or a part that is no longer solved.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Regression {
+    pub day: Day,
+    /// The regressed part, or `None` if the total runtime of the day regressed.
+    pub part: Option<u8>,
+    pub kind: RegressionKind,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RegressionKind {
+    /// The part or day got slower.
+    Slower { stored_nanos: f64, new_nanos: f64 },
+    /// The part was solved before. Holds its new status, or `None` if it did not run.
+    Lost(Option<PartStatus>),
+}
+
+impl Regression {
+    /// Slowdown relative to the stored timing, in percent. `None` if the part is no longer solved.
+    pub fn slowdown(&self) -> Option<f64> {
+        match self.kind {
+            RegressionKind::Slower {
+                stored_nanos,
+                new_nanos,
+            } => Some((new_nanos - stored_nanos) / stored_nanos * 100.0),
+            RegressionKind::Lost(_) => None,
+        }
+    }
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.part {
+            Some(part) => write!(f, "Day {} (Part {part})", self.day)?,
+            None => write!(f, "Day {}", self.day)?,
+        }
+
+        match (&self.kind, self.slowdown()) {
+            (
+                RegressionKind::Slower {
+                    stored_nanos,
+                    new_nanos,
+                },
+                Some(slowdown),
+            ) => write!(
+                f,
+                ": {} -> {} (+{slowdown:.1}%)",
+                format_nanos(*stored_nanos),
+                format_nanos(*new_nanos),
+            ),
+            (RegressionKind::Lost(Some(status)), _) => write!(f, ": solved -> {status}"),
+            _ => write!(f, ": solved -> did not run"),
+        }
+    }
+}
+
+/// Compare `new` timings against `stored` timings and collect those that regressed by more than `threshold` percent.
+/// Parts with benchmark statistics are compared by their median, taking their spread into account.
+/// Otherwise, the total runtime of a day is compared, if both timings cover the same solved parts.
+/// A part that was solved before but timed out, ran out of memory, crashed, returned no answer or did not run is always a regression.
+pub fn find_regressions(stored: &Timings, new: &Timings, threshold: f64) -> Vec<Regression> {
+    new.data
+        .iter()
+        .filter_map(|new| {
+            let stored = stored.data.iter().find(|t| t.day == new.day)?;
+            Some(compare_timing(stored, new, threshold))
+        })
+        .flatten()
+        .collect()
+}
+
+fn compare_timing(stored: &Timing, new: &Timing, threshold: f64) -> Vec<Regression> {
+    let lost = lost_parts(stored, new);
+    if !lost.is_empty() {
+        return lost;
+    }
+
+    let parts = [
+        (1, &stored.part_1_stats, &new.part_1_stats),
+        (2, &stored.part_2_stats, &new.part_2_stats),
+    ];
+
+    let has_stats = parts.iter().any(|(_, s, n)| s.is_some() && n.is_some());
+
+    if !has_stats {
+        // a total that includes another part is not comparable.
+        let is_regression = solved_parts(stored) == solved_parts(new)
+            && is_slower(stored.total_nanos, new.total_nanos, 0.0, threshold);
+        return if is_regression {
+            vec![Regression {
+                day: new.day,
+                part: None,
+                kind: RegressionKind::Slower {
+                    stored_nanos: stored.total_nanos,
+                    new_nanos: new.total_nanos,
+                },
+            }]
+        } else {
+            vec![]
+        };
+    }
+
+    parts
+        .into_iter()
+        .filter_map(|(part, stored_stats, new_stats)| {
+            let (stored_stats, new_stats) = (stored_stats.as_ref()?, new_stats.as_ref()?);
+            let noise = combined_noise(stored_stats, new_stats);
+
+            is_slower(stored_stats.median, new_stats.median, noise, threshold).then_some(
+                Regression {
+                    day: new.day,
+                    part: Some(part),
+                    kind: RegressionKind::Slower {
+                        stored_nanos: stored_stats.median,
+                        new_nanos: new_stats.median,
+                    },
+                },
+            )
+        })
+        .collect()
+}
+
+/// Parts that were solved in `stored` but are not solved in `new`.
+fn lost_parts(stored: &Timing, new: &Timing) -> Vec<Regression> {
+    let (stored_parts, new_parts) = (solved_parts(stored), solved_parts(new));
+
+    [
+        (1, stored_parts.0, new_parts.0, new.part_1_status),
+        (2, stored_parts.1, new_parts.1, new.part_2_status),
+    ]
+    .into_iter()
+    .filter(|(_, was_solved, is_solved, _)| *was_solved && !is_solved)
+    .map(|(part, _, _, status)| Regression {
+        day: new.day,
+        part: Some(part),
+        kind: RegressionKind::Lost(status),
+    })
+    .collect()
+}
+
+fn solved_parts(timing: &Timing) -> (bool, bool) {
+    (timing.part_1.is_some(), timing.part_2.is_some())
+}
+
+fn combined_noise(a: &BenchStats, b: &BenchStats) -> f64 {
+    NOISE_SIGMAS * (a.std_dev.powi(2) + b.std_dev.powi(2)).sqrt()
+}
+
+fn is_slower(stored: f64, new: f64, noise: f64, threshold: f64) -> bool {
+    stored > 0.0 && new > stored * (1.0 + threshold / 100.0) && new - stored > noise
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{find_regressions, Regression, RegressionKind};
+    use crate::day;
+    use crate::template::records::PartStatus;
+    use crate::template::stats::BenchStats;
+    use crate::template::timings::{Timing, Timings};
+
+    fn stats(median: f64, std_dev: f64) -> BenchStats {
+        BenchStats {
+            median,
+            mean: median,
+            min: median,
+            max: median,
+            std_dev,
+            p95: median,
+            samples: 100,
+            outliers: 0,
+        }
+    }
+
+    fn timings(total_nanos: f64, part_1_stats: Option<BenchStats>) -> Timings {
+        Timings {
+            data: vec![Timing {
+                day: day!(1),
+                part_1: Some("1ms".into()),
+                part_2: None,
+                part_1_stats,
+                part_2_stats: None,
+                part_1_status: None,
+                part_2_status: None,
+                part_1_allocs: None,
+                part_2_allocs: None,
+                total_nanos,
+            }],
+            history: vec![],
+        }
+    }
+
+    #[test]
+    fn detects_slower_medians() {
+        let stored = timings(1000.0, Some(stats(1000.0, 10.0)));
+        let new = timings(1500.0, Some(stats(1500.0, 10.0)));
+        assert_eq!(
+            find_regressions(&stored, &new, 10.0),
+            vec![Regression {
+                day: day!(1),
+                part: Some(1),
+                kind: RegressionKind::Slower {
+                    stored_nanos: 1000.0,
+                    new_nanos: 1500.0,
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn tolerates_slowdowns_below_threshold() {
+        let stored = timings(1000.0, Some(stats(1000.0, 0.0)));
+        let new = timings(1050.0, Some(stats(1050.0, 0.0)));
+        assert_eq!(find_regressions(&stored, &new, 10.0).len(), 0);
+    }
+
+    #[test]
+    fn tolerates_slowdowns_within_noise() {
+        let stored = timings(1000.0, Some(stats(1000.0, 200.0)));
+        let new = timings(1500.0, Some(stats(1500.0, 200.0)));
+        assert_eq!(find_regressions(&stored, &new, 10.0).len(), 0);
+    }
+
+    #[test]
+    fn compares_totals_without_stats() {
+        let stored = timings(1000.0, None);
+        let new = timings(2000.0, None);
+        let regressions = find_regressions(&stored, &new, 10.0);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].part, None);
+        assert_eq!(regressions[0].slowdown(), Some(100.0));
+    }
+
+    #[test]
+    fn ignores_totals_of_different_parts() {
+        let stored = timings(1000.0, None);
+        let mut new = timings(2000.0, None);
+        new.data[0].part_2 = Some("1ms".into());
+        assert_eq!(find_regressions(&stored, &new, 10.0).len(), 0);
+    }
+
+    #[test]
+    fn detects_parts_no_longer_solved() {
+        let stored = timings(1000.0, Some(stats(1000.0, 10.0)));
+        let mut new = timings(500.0, None);
+        new.data[0].part_1 = None;
+        new.data[0].part_1_status = Some(PartStatus::TimedOut);
+        assert_eq!(
+            find_regressions(&stored, &new, 10.0),
+            vec![Regression {
+                day: day!(1),
+                part: Some(1),
+                kind: RegressionKind::Lost(Some(PartStatus::TimedOut)),
+            }]
+        );
+        assert_eq!(
+            find_regressions(&stored, &new, 10.0)[0].to_string(),
+            "Day 01 (Part 1): solved -> timed out"
+        );
+    }
+
+    #[test]
+    fn detects_parts_that_did_not_run() {
+        let stored = timings(1000.0, None);
+        let mut new = timings(0.0, None);
+        new.data[0].part_1 = None;
+        let regressions = find_regressions(&stored, &new, 10.0);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].kind, RegressionKind::Lost(None));
+        assert_eq!(regressions[0].slowdown(), None);
+    }
+
+    #[test]
+    fn ignores_new_days() {
+        let new = timings(2000.0, None);
+        assert_eq!(find_regressions(&Timings::default(), &new, 10.0).len(), 0);
+    }
+
+    #[test]
+    fn ignores_speedups() {
+        let stored = timings(2000.0, Some(stats(2000.0, 0.0)));
+        let new = timings(1000.0, Some(stats(1000.0, 0.0)));
+        assert_eq!(find_regressions(&stored, &new, 10.0).len(), 0);
+    }
+}
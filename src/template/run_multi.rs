@@ -1,48 +1,254 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{HashMap, HashSet},
+    io,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    process,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Mutex,
+    },
+    thread,
+};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{
+    bin_name, bin_path, is_configured_year, read_file, Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
 
 use super::{
     all_days,
+    limits::LimitOverrides,
+    records::PartRecord,
+    runner::{report_empty_input, RunOptions, Solution},
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
-
-    let mut need_space = false;
+pub fn run_multi(
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    options: &RunOptions,
+    jobs: Option<usize>,
+    solutions: &[Solution],
+) -> Option<Timings> {
+    let results = run_days(days_to_run, is_release, options, jobs, solutions);
+
+    if options.is_timed {
+        let timings = Timings {
+            data: results
+                .iter()
+                .filter(|(_, records)| !records.is_empty())
+                .map(|(day, records)| Timing::from_records(*day, records))
+                .collect(),
+            history: vec![],
+        };
+        let total_millis = timings.total_millis();
+        println!(
+            "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+        );
+        Some(timings)
+    } else {
+        None
+    }
+}
 
+/// Build all solution bins once, then run the selected days with up to `jobs` solutions at a time, one per CPU by default.
+/// If solutions were registered with the `registry` feature, they are run sequentially in-process instead.
+/// Only solutions of the configured year are registered, other years always run as child processes.
+/// Output is printed grouped per day, in day order. Returns the records emitted by each day.
+pub fn run_days(
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    options: &RunOptions,
+    jobs: Option<usize>,
+    solutions: &[Solution],
+) -> Vec<(Day, Vec<PartRecord>)> {
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
+    let days: Vec<Day> = all_days().filter(|day| days_to_run.contains(day)).collect();
+
+    let overrides = LimitOverrides::read_from_file().unwrap_or_else(|e| {
+        eprintln!("Failed to read limits: {e}");
+        process::exit(1);
+    });
+
+    if !solutions.is_empty() && is_configured_year() {
+        if !options.limits.is_empty() || !overrides.is_empty() {
+            eprintln!("Warning: timeouts and memory limits are not enforced for solutions that run in-process.");
+        }
+        if options.count_allocs {
+            eprintln!("Warning: allocations are not counted for solutions that run in-process.");
+        }
+        if jobs.is_some_and(|jobs| jobs > 1) {
+            eprintln!(
+                "Warning: solutions that run in-process run one at a time, `--jobs` is ignored."
+            );
+        }
+        // in-process solutions are compiled with this binary, in its profile.
+        if is_release && cfg!(debug_assertions) {
+            eprintln!("Warning: solutions that run in-process are not built in release mode. Run this command with `cargo run --release`.");
+        }
+        return run_in_process(&days, solutions, options);
+    }
+
+    // the counting allocator slows down every allocation, so benched days count them in a separate, untimed run.
+    let count_separately = options.is_timed && options.count_allocs;
+
+    let executables =
+        child_commands::build_solutions(is_release, options.count_allocs && !count_separately)
+            .unwrap();
+    let alloc_executables = if count_separately {
+        child_commands::build_solutions(is_release, true).unwrap()
+    } else {
+        HashMap::new()
+    };
+
+    let run_day = |day: Day, forward: &(dyn Fn(String) + Sync)| match executables
+        .get(&bin_name(day))
+    {
+        Some(executable) => {
+            let options = RunOptions {
+                limits: overrides.apply(day, &options.limits),
+                ..options.clone()
+            };
+            let mut records = child_commands::run_solution(executable, &options, forward).unwrap();
+
+            if let Some(executable) = alloc_executables.get(&bin_name(day)) {
+                let options = RunOptions {
+                    is_timed: false,
+                    ..options
+                };
+                let counted = child_commands::run_solution(executable, &options, &|_| {}).unwrap();
+                add_allocs(&mut records, &counted, forward);
             }
-            need_space = true;
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+            records
+        }
+        // days that have not been scaffolded or failed to compile do not have an executable.
+        None => vec![],
+    };
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    let jobs = jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, usize::from));
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+    if jobs <= 1 {
+        // stream output while it is produced.
+        return days
+            .iter()
+            .enumerate()
+            .map(|(i, day)| {
+                print_header(i, *day);
+                let records = run_day(*day, &|line| println!("{line}"));
+                print_footer(&records);
+                (*day, records)
+            })
+            .collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+    let mut results = Vec::with_capacity(days.len());
+
+    thread::scope(|s| {
+        for _ in 0..jobs.min(days.len()) {
+            let tx = tx.clone();
+            let (next, days, run_day) = (&next, &days, &run_day);
+
+            s.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::SeqCst);
+                let Some(day) = days.get(i) else {
+                    break;
+                };
+
+                let lines = Mutex::new(vec![]);
+                let records = run_day(*day, &|line| lines.lock().unwrap().push(line));
+
+                if tx.send((i, lines.into_inner().unwrap(), records)).is_err() {
+                    break;
+                }
+            });
+        }
+
+        drop(tx);
+
+        // print finished days in order, buffering those that complete early.
+        let mut pending = HashMap::new();
+
+        for (i, lines, records) in rx {
+            pending.insert(i, (lines, records));
+
+            while let Some((lines, records)) = pending.remove(&results.len()) {
+                let (i, day) = (results.len(), days[results.len()]);
+                print_header(i, day);
+                lines.iter().for_each(|line| println!("{line}"));
+                print_footer(&records);
+                results.push((day, records));
             }
-        });
+        }
+    });
 
-    if is_timed {
-        let timings = Timings { data: timings };
-        let total_millis = timings.total_millis();
-        println!(
-            "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
-        );
-        Some(timings)
-    } else {
-        None
+    results
+}
+
+/// Run registered solutions in the current process, avoiding process startup and cargo overhead.
+fn run_in_process(
+    days: &[Day],
+    solutions: &[Solution],
+    options: &RunOptions,
+) -> Vec<(Day, Vec<PartRecord>)> {
+    days.iter()
+        .enumerate()
+        .map(|(i, day)| {
+            print_header(i, *day);
+
+            let records = solutions
+                .iter()
+                .find(|solution| solution.day == *day)
+                .and_then(|solution| {
+                    // a panicking solution should not abort the remaining days.
+                    panic::catch_unwind(AssertUnwindSafe(|| {
+                        let input = read_file("inputs", *day);
+                        if input.is_empty() {
+                            report_empty_input(*day);
+                            return vec![];
+                        }
+                        (solution.run)(&input, options)
+                    }))
+                    .ok()
+                })
+                .unwrap_or_default();
+
+            print_footer(&records);
+            (*day, records)
+        })
+        .collect()
+}
+
+fn print_header(i: usize, day: Day) {
+    if i > 0 {
+        println!();
+    }
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+}
+
+/// Add the allocations counted in a separate run to the records of the timed run.
+fn add_allocs(records: &mut [PartRecord], counted: &[PartRecord], forward: &dyn Fn(String)) {
+    for (record, counted) in records.iter_mut().zip(counted) {
+        if record.part != counted.part {
+            continue;
+        }
+
+        if let Some(allocs) = &counted.allocs {
+            forward(format!(
+                "Part {}: {ANSI_ITALIC}{allocs}{ANSI_RESET}",
+                record.part
+            ));
+            record.allocs = Some(allocs.clone());
+        }
+    }
+}
+
+fn print_footer(records: &[PartRecord]) {
+    if records.is_empty() {
+        println!("Not solved.");
     }
 }
 
@@ -61,197 +267,326 @@ impl From<std::io::Error> for Error {
 
 #[must_use]
 pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+    format!("./{}", bin_path(day))
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both building and invoking them as well as collecting the records they emit.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use super::{Error, PathBuf};
+    use crate::template::records::{PartRecord, PartStatus};
+    use crate::template::runner::RunOptions;
+    use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
     use std::{
+        collections::HashMap,
+        env,
         io::{BufRead, BufReader},
         path::Path,
-        process::{Command, Stdio},
+        process::{Command, ExitStatus, Stdio},
+        str::FromStr,
+        sync::mpsc::{self, RecvTimeoutError},
         thread,
+        time::Instant,
     };
+    use tinyjson::JsonValue;
+
+    /// Build all solution bins with a single cargo invocation, optionally with allocation counting.
+    /// Returns the path of each compiled executable, keyed by bin name.
+    pub fn build_solutions(
+        is_release: bool,
+        count_allocs: bool,
+    ) -> Result<HashMap<String, PathBuf>, Error> {
+        // `--keep-going` makes sure that a single broken day does not prevent the other days from running.
+        let mut args = vec![
+            "build",
+            "--quiet",
+            "--bins",
+            "--keep-going",
+            "--message-format=json-render-diagnostics",
+        ];
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+        if is_release {
+            args.push("--release");
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        // alloc-count builds get their own target directory, so that they don't replace the regular bins.
+        let target_dir = env::var("CARGO_TARGET_DIR").unwrap_or_else(|_| "target".into());
+        let target_dir = format!("{target_dir}/alloc-count");
 
-        if is_release {
-            args.push("--release");
+        if count_allocs {
+            args.extend(["--features", "alloc-count", "--target-dir", &target_dir]);
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        let output = Command::new("cargo")
+            .args(&args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .output()?;
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(parse_executable)
+            .collect())
+    }
+
+    /// Parse a `compiler-artifact` message emitted by cargo into the bin name and its executable.
+    fn parse_executable(line: &str) -> Option<(String, PathBuf)> {
+        let json = JsonValue::from_str(line).ok()?;
+        let message = json.get::<HashMap<String, JsonValue>>()?;
+
+        if message.get("reason")?.get::<String>()? != "compiler-artifact" {
+            return None;
         }
 
+        let executable = message.get("executable")?.get::<String>()?;
+        let name = message
+            .get("target")?
+            .get::<HashMap<String, JsonValue>>()?
+            .get("name")?
+            .get::<String>()?;
+
+        Some((name.clone(), PathBuf::from(executable)))
+    }
+
+    /// Lines a solution emits on stdout or stderr.
+    enum Line {
+        Stdout(String),
+        Stderr(String),
+    }
+
+    /// Rust prints this to stderr before aborting when an allocation fails.
+    const ALLOC_FAILURE_PREFIX: &str = "memory allocation of";
+
+    /// Run a compiled solution bin, passing each line of human-readable output to `forward`.
+    /// A solution that exceeds its limits is killed, and the part it was working on is reported as aborted.
+    pub fn run_solution(
+        executable: &Path,
+        options: &RunOptions,
+        forward: &(dyn Fn(String) + Sync),
+    ) -> Result<Vec<PartRecord>, Error> {
+        // mirror run options to child invocations, asking for machine-readable records on stdout.
+        let args = RunOptions {
+            is_json: true,
+            ..options.clone()
+        }
+        .to_args();
+
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // in `--json` mode, stderr carries the human-readable output.
+        // stdout carries one record per part; other lines are forwarded as-is.
 
-        let mut cmd = Command::new("cargo")
+        let mut command = Command::new(executable);
+        command
             .args(&args)
             .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+            .stderr(Stdio::piped());
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+        if let Some(bytes) = options.limits.memory {
+            limit_memory(&mut command, bytes);
+        }
 
-        let mut output = vec![];
+        let mut cmd = command.spawn()?;
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
+        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
-        }
+        let mut records = vec![];
+        let mut aborted: Option<PartStatus> = None;
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        let day_deadline = options.limits.day_timeout.map(|t| Instant::now() + t);
+        let mut part_start = Instant::now();
 
-        Ok(output)
-    }
+        thread::scope(|s| -> Result<(), Error> {
+            let (tx, rx) = mpsc::channel();
+            let tx_stderr = tx.clone();
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+            s.spawn(move || {
+                for line in stderr.lines().map_while(Result::ok) {
+                    if tx_stderr.send(Line::Stderr(line)).is_err() {
+                        break;
+                    }
+                }
+            });
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
+            s.spawn(move || {
+                for line in stdout.lines().map_while(Result::ok) {
+                    if tx.send(Line::Stdout(line)).is_err() {
+                        break;
+                    }
                 }
+            });
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
+            loop {
+                // once the child was killed, drain its remaining output.
+                let deadline = if aborted.is_some() {
+                    None
+                } else {
+                    let part = records.last().map_or(1, |r: &PartRecord| r.part + 1);
+                    let part_deadline =
+                        options.limits.part_timeout_of(part).map(|t| part_start + t);
+                    part_deadline.into_iter().chain(day_deadline).min()
                 };
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+                let line = match deadline {
+                    Some(deadline) => {
+                        match rx.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                            Ok(line) => line,
+                            Err(RecvTimeoutError::Timeout) => {
+                                cmd.kill()?;
+                                aborted = Some(PartStatus::TimedOut);
+                                continue;
+                            }
+                            Err(RecvTimeoutError::Disconnected) => break,
+                        }
+                    }
+                    None => match rx.recv() {
+                        Ok(line) => line,
+                        Err(_) => break,
+                    },
+                };
+
+                match line {
+                    Line::Stdout(line) => match PartRecord::try_from(line.as_str()) {
+                        Ok(record) => {
+                            records.push(record);
+                            part_start = Instant::now();
+                        }
+                        Err(_) => forward(line),
+                    },
+                    Line::Stderr(line) => {
+                        if line.starts_with(ALLOC_FAILURE_PREFIX) && aborted.is_none() {
+                            aborted = Some(PartStatus::OutOfMemory);
+                        }
+                        forward(line);
+                    }
                 }
+            }
 
-                timings.total_nanos += nanos;
-            });
+            Ok(())
+        })?;
+
+        let exit_status = cmd.wait()?;
+        let aborted = aborted.or_else(|| {
+            exited_abnormally(
+                &exit_status,
+                records.is_empty(),
+                options.limits.memory.is_some(),
+            )
+        });
+
+        if let Some(status) = aborted {
+            let part = records.last().map_or(1, |r: &PartRecord| r.part + 1);
+            if part <= 2 {
+                let record = PartRecord::aborted(part, status, &part_start.elapsed());
+                forward(format_aborted(&record));
+                records.push(record);
+            }
+        }
 
-        timings
+        Ok(records)
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    /// Status of the part in progress when the child exited with `status`, if it did not exit cleanly.
+    /// A child killed by a signal, e.g. on a failed allocation or a stack overflow, most likely ran out of memory if it was limited.
+    /// A child that exits with 1 before any part ran failed to read its input and already said why.
+    fn exited_abnormally(
+        status: &ExitStatus,
+        is_first_part: bool,
+        is_memory_limited: bool,
+    ) -> Option<PartStatus> {
+        match status.code() {
+            _ if status.success() => None,
+            Some(1) if is_first_part => None,
+            Some(_) => Some(PartStatus::Crashed),
+            None if is_memory_limited => Some(PartStatus::OutOfMemory),
+            None => Some(PartStatus::Crashed),
+        }
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    fn format_aborted(record: &PartRecord) -> String {
+        format!(
+            "\rPart {}: {ANSI_BOLD}{}{ANSI_RESET} {ANSI_ITALIC}(after {}){ANSI_RESET}",
+            record.part, record.status, record.duration
+        )
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    /// Limit the address space of the child, so that allocations beyond the limit fail.
+    #[cfg(target_os = "linux")]
+    fn limit_memory(command: &mut Command, bytes: u64) {
+        use std::os::unix::process::CommandExt;
+
+        let limit = libc::rlimit {
+            rlim_cur: bytes,
+            rlim_max: bytes,
+        };
+
+        // SAFETY: `setrlimit` is async-signal-safe and does not allocate.
+        unsafe {
+            command.pre_exec(move || {
+                if libc::setrlimit(libc::RLIMIT_AS, &limit) == 0 {
+                    Ok(())
+                } else {
+                    Err(std::io::Error::last_os_error())
+                }
+            });
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn limit_memory(_command: &mut Command, _bytes: u64) {
+        eprintln!("Warning: memory limits are only enforced on Linux.");
     }
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
+        use super::parse_executable;
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+        fn parses_bin_artifacts() {
+            let line = r#"{"reason":"compiler-artifact","target":{"kind":["bin"],"name":"01"},"executable":"/repo/target/release/01","fresh":true}"#;
+            let (name, executable) = parse_executable(line).unwrap();
+            assert_eq!(name, "01");
+            assert_eq!(executable.to_str().unwrap(), "/repo/target/release/01");
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+        fn ignores_other_messages() {
+            let lib = r#"{"reason":"compiler-artifact","target":{"kind":["lib"],"name":"advent_of_code"},"executable":null}"#;
+            assert!(parse_executable(lib).is_none());
+            let finished = r#"{"reason":"build-finished","success":true}"#;
+            assert!(parse_executable(finished).is_none());
+            assert!(parse_executable("warning: unused").is_none());
         }
 
+        #[cfg(target_os = "linux")]
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
+        fn classifies_abnormal_exits() {
+            use super::exited_abnormally;
+            use crate::template::records::PartStatus;
+            use std::os::unix::process::ExitStatusExt;
+            use std::process::ExitStatus;
+
+            let exit = |code: i32| ExitStatus::from_raw(code << 8);
+            let signal = |signal: i32| ExitStatus::from_raw(signal);
+
+            assert_eq!(exited_abnormally(&exit(0), true, true), None);
+            assert_eq!(exited_abnormally(&exit(1), true, false), None);
+            assert_eq!(
+                exited_abnormally(&exit(1), false, false),
+                Some(PartStatus::Crashed)
+            );
+            assert_eq!(
+                exited_abnormally(&exit(101), true, false),
+                Some(PartStatus::Crashed)
+            );
+            assert_eq!(
+                exited_abnormally(&signal(libc::SIGSEGV), true, true),
+                Some(PartStatus::OutOfMemory)
+            );
+            assert_eq!(
+                exited_abnormally(&signal(libc::SIGABRT), false, false),
+                Some(PartStatus::Crashed)
             );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
         }
     }
 }
@@ -1,65 +1,508 @@
 /// Encapsulates code that interacts with solution functions.
 use std::fmt::Display;
 use std::hint::black_box;
-use std::io::{stdout, Write};
-use std::process::Output;
+use std::io::{self, stderr, stdout, Write};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, fs, process};
 
+use crate::template::alloc::{self, AllocStats};
+use crate::template::answers::{format_cell, Answers, Verification};
+use crate::template::examples::{ExampleManifest, Expected};
+use crate::template::records::PartRecord;
+use crate::template::stats::{format_nanos, BenchStats};
+use crate::template::submissions::{self, format_wait, Submission, Submissions, Verdict};
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{
+    aoc_cli, aoc_client, data_dir_for, list_inputs, Day, ANSI_ITALIC, ANSI_RESET,
+};
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Options that control how solution parts are run.
+/// Solution bins read them from their command-line arguments, in-process runs construct them directly.
+#[derive(Clone, Debug)]
+pub struct RunOptions {
+    /// Bench each part instead of running it once.
+    pub is_timed: bool,
+    /// Approximate time spent benching each part.
+    pub bench_time: Duration,
+    /// Emit a machine-readable record per part on stdout and human-readable output on stderr.
+    pub is_json: bool,
+    /// Submit the result of this part.
+    pub submit: Option<u8>,
+    /// Resource limits, enforced by the runner when solutions run as child processes.
+    pub limits: Limits,
+    /// Build solution bins with the `alloc-count` feature, so that they report their allocations.
+    pub count_allocs: bool,
+    /// Read the input from this path instead of `data/inputs`. `-` reads from stdin.
+    pub input: Option<String>,
+    /// Name of the named input that is read, used to look up its stored answers.
+    pub input_name: Option<String>,
+    /// Run every named input in `data/inputs/NN/`.
+    pub all_inputs: bool,
+    /// Read an example from `data/examples` instead of the input.
+    /// `Some(None)` reads `NN.txt`, `Some(Some(n))` reads `NN-n.txt`.
+    pub example: Option<Option<u8>>,
+}
+
+/// Resource limits for solution bins.
+#[derive(Clone, Debug, Default)]
+pub struct Limits {
+    /// Wall-clock time each part may take.
+    pub part_timeout: Option<Duration>,
+    /// Wall-clock time part 1 and part 2 may take, overriding `part_timeout`.
+    pub part_timeouts: [Option<Duration>; 2],
+    /// Wall-clock time all parts of a day may take together.
+    pub day_timeout: Option<Duration>,
+    /// Maximum address space of a solution process, in bytes. Only enforced on Linux.
+    pub memory: Option<u64>,
+}
+
+impl Limits {
+    pub fn is_empty(&self) -> bool {
+        self.part_timeout.is_none()
+            && self.part_timeouts.iter().all(Option::is_none)
+            && self.day_timeout.is_none()
+            && self.memory.is_none()
+    }
+
+    /// Wall-clock time a part may take.
+    pub fn part_timeout_of(&self, part: u8) -> Option<Duration> {
+        match part {
+            1 | 2 => self.part_timeouts[usize::from(part - 1)].or(self.part_timeout),
+            _ => self.part_timeout,
+        }
+    }
+
+    /// Converts a memory limit in MiB to bytes, returns [`None`] if it does not fit.
+    pub fn bytes_from_mib(mib: u64) -> Option<u64> {
+        mib.checked_mul(1024 * 1024)
+    }
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        RunOptions {
+            is_timed: false,
+            bench_time: Duration::from_secs(1),
+            is_json: false,
+            submit: None,
+            limits: Limits::default(),
+            count_allocs: false,
+            input: None,
+            input_name: None,
+            all_inputs: false,
+            example: None,
+        }
+    }
+}
+
+impl RunOptions {
+    /// Parse the arguments passed to a solution bin.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        let submit = args.iter().position(|x| x == "--submit").map(|index| {
+            let Some(Ok(part)) = args.get(index + 1).map(|x| x.parse::<u8>()) else {
+                eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+                process::exit(1);
+            };
+            part
+        });
+
+        let bench_time = args.iter().position(|x| x == "--bench-time").map(|index| {
+            let Some(bench_time) = args
+                .get(index + 1)
+                .and_then(|x| x.parse::<f64>().ok())
+                .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+            else {
+                eprintln!("Unexpected command-line input. Format: --bench-time 2.5");
+                process::exit(1);
+            };
+            bench_time
+        });
+
+        let input = args.iter().position(|x| x == "--input").map(|index| {
+            let Some(path) = args.get(index + 1) else {
+                eprintln!("Unexpected command-line input. Format: cargo solve 1 --input <path>");
+                process::exit(1);
+            };
+            path.clone()
+        });
+
+        // the example number is optional: `--example` reads `NN.txt`, `--example 2` reads `NN-2.txt`.
+        let example = args
+            .iter()
+            .position(|x| x == "--example")
+            .map(|index| args.get(index + 1).and_then(|x| x.parse::<u8>().ok()));
+
+        if example.is_some() && (input.is_some() || args.iter().any(|x| x == "--all-inputs")) {
+            eprintln!("`--example` can not be combined with `--input` or `--all-inputs`.");
+            process::exit(1);
+        }
+
+        RunOptions {
+            is_timed: args.iter().any(|x| x == "--time"),
+            bench_time: bench_time.unwrap_or(RunOptions::default().bench_time),
+            is_json: args.iter().any(|x| x == "--json"),
+            submit,
+            input,
+            all_inputs: args.iter().any(|x| x == "--all-inputs"),
+            example,
+            ..RunOptions::default()
+        }
+    }
+
+    /// File name of the selected example relative to `data/examples`, e.g. `05-2.txt`.
+    pub fn example_file(&self, day: Day) -> Option<String> {
+        self.example.map(|number| match number {
+            Some(number) => format!("{day}-{number}.txt"),
+            None => format!("{day}.txt"),
+        })
+    }
+
+    /// Whether parts run against the puzzle input in `data/inputs`.
+    pub fn is_puzzle_input(&self) -> bool {
+        self.input.is_none() && self.example.is_none()
+    }
+
+    /// Read the input of a day, either from `--example`, `--input` or `data/inputs`.
+    pub fn read_input(&self, day: Day) -> String {
+        if let Some(file) = self.example_file(day) {
+            let path = env::current_dir()
+                .unwrap()
+                .join(data_dir_for(Some(day)))
+                .join("examples")
+                .join(&file);
+
+            return fs::read_to_string(path).unwrap_or_else(|err| {
+                eprintln!("Could not read example `{file}`: {err}");
+                process::exit(1);
+            });
+        }
+
+        let Some(path) = &self.input else {
+            // a missing input is reported like an empty one instead of panicking, so runners don't take it for a crash.
+            let path = env::current_dir()
+                .unwrap()
+                .join(data_dir_for(Some(day)))
+                .join("inputs")
+                .join(format!("{day}.txt"));
+            let input = fs::read_to_string(path).unwrap_or_default();
+            if input.is_empty() {
+                report_empty_input(day);
+                process::exit(1);
+            }
+            return input;
+        };
+
+        let result = if path == "-" {
+            io::read_to_string(io::stdin())
+        } else {
+            fs::read_to_string(path)
+        };
+
+        result.unwrap_or_else(|err| {
+            eprintln!("Could not read input `{path}`: {err}");
+            process::exit(1);
+        })
+    }
+
+    /// Arguments that pass these options on to a solution bin.
+    /// Limits are not passed on, they are enforced by the runner.
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+
+        if self.is_json {
+            args.push("--json".into());
+        }
+
+        if self.is_timed {
+            args.push("--time".into());
+            args.push("--bench-time".into());
+            args.push(self.bench_time.as_secs_f64().to_string());
+        }
+
+        if let Some(input) = &self.input {
+            args.push("--input".into());
+            args.push(input.clone());
+        }
+
+        if self.all_inputs {
+            args.push("--all-inputs".into());
+        }
+
+        if let Some(number) = self.example {
+            args.push("--example".into());
+            args.extend(number.map(|n| n.to_string()));
+        }
+
+        if let Some(part) = self.submit {
+            args.push("--submit".into());
+            args.push(part.to_string());
+        }
+
+        args
+    }
+}
+
+/// A solution registered for in-process execution, see the `registry` feature.
+pub struct Solution {
+    pub day: Day,
+    /// Runs all parts of the solution against an input.
+    pub run: fn(&str, &RunOptions) -> Vec<PartRecord>,
+}
+
+pub fn run_part<I: Clone, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    day: Day,
+    part: u8,
+    options: &RunOptions,
+) -> PartRecord {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, duration, samples, stats, allocs) = run_timed(func, input, options, |result| {
+        print_result(result, &part_str, "", options);
+    });
+
+    let answer = result.as_ref().map(ToString::to_string);
+
+    // stored answers belong to our own input or to a named input, examples are checked against their manifest.
+    let verification = match (&options.input, &options.input_name) {
+        _ if options.example.is_some() => verify_example(day, part, answer.as_deref(), options),
+        (None, _) => Some(read_answers().verify(day, part, answer.as_deref())),
+        (Some(_), Some(name)) => {
+            Some(read_answers().verify_for_input(day, Some(name), part, answer.as_deref()))
+        }
+        (Some(_), None) => None,
+    };
+
+    print_result(
+        &result,
+        &part_str,
+        &format!(
+            "{}{}",
+            format_duration(&duration, samples, stats.as_ref()),
+            verification
+                .as_ref()
+                .map_or_else(String::new, format_verification)
+        ),
+        options,
+    );
+
+    if let Some(Verification::Incorrect { expected }) = &verification {
+        print_mismatch(result.as_ref(), expected, &part_str);
+    }
+
+    if let Some(stats) = &stats {
+        print_stats(stats, options);
+    }
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    if let Some(allocs) = &allocs {
+        print_allocs(allocs, options);
+    }
+
+    let record = PartRecord {
+        allocs,
+        ..PartRecord::new(part, answer, &duration, samples, stats)
+    };
+
+    if options.is_json {
+        println!("{}", record.to_json_line());
+    }
 
     if let Some(result) = result {
-        submit_result(result, day, part);
+        submit_result(result, day, part, options);
     }
+
+    record
+}
+
+/// Check a result against the expected answer in the example manifest `data/examples/NN.json`.
+/// Returns `None` if the manifest does not list the example for this part.
+fn verify_example(
+    day: Day,
+    part: u8,
+    answer: Option<&str>,
+    options: &RunOptions,
+) -> Option<Verification> {
+    let file = options.example_file(day)?;
+    let path = env::current_dir()
+        .unwrap()
+        .join(data_dir_for(Some(day)))
+        .join("examples")
+        .join(format!("{day}.json"));
+
+    let manifest = match ExampleManifest::read_from_file(&path) {
+        Ok(manifest) => manifest?,
+        Err(e) => {
+            eprintln!("Could not read example manifest `{}`: {e}", path.display());
+            return None;
+        }
+    };
+
+    let expected = manifest
+        .examples
+        .iter()
+        .find(|example| example.file == file)?
+        .expected(part)?;
+
+    Some(match (expected, answer) {
+        (Expected::Answer(expected), Some(answer)) if expected == answer => Verification::Correct,
+        (Expected::Answer(expected), _) => Verification::Incorrect {
+            expected: expected.clone(),
+        },
+        (Expected::Unsolved, None) => Verification::Correct,
+        (Expected::Unsolved, Some(_)) => Verification::Incorrect {
+            expected: "None".into(),
+        },
+    })
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// Run a solution against every named input in `data/inputs/NN/` and print whether each matches its stored answers.
+/// Exits with a non-zero status if an answer does not match.
+pub fn run_all_inputs(
+    day: Day,
+    run: fn(&str, &RunOptions) -> Vec<PartRecord>,
+    options: &RunOptions,
+) {
+    let inputs = list_inputs(day);
+
+    if inputs.is_empty() {
+        eprintln!("No named inputs found. Add them as `data/inputs/{day}/<name>.txt`.");
+        process::exit(1);
+    }
+
+    let answers = read_answers();
+    let mut rows: Vec<(String, [String; 2])> = vec![];
+    let mut mismatches = 0;
+
+    for (i, (name, path)) in inputs.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        println!("{ANSI_BOLD}Input {name}{ANSI_RESET}");
+        println!("------");
+
+        let options = RunOptions {
+            input: Some(path.display().to_string()),
+            input_name: Some(name.clone()),
+            all_inputs: false,
+            ..options.clone()
+        };
+
+        let records = run(&options.read_input(day), &options);
+
+        let cells = [1, 2].map(|part| {
+            let answer = records
+                .iter()
+                .find(|r| r.part == part)
+                .and_then(|r| r.answer.as_deref());
+            let verification = answers.verify_for_input(day, Some(name), part, answer);
+
+            if matches!(verification, Verification::Incorrect { .. }) {
+                mismatches += 1;
+            }
+
+            format_cell(&verification, answer.is_some())
+        });
+
+        rows.push((name.clone(), cells));
+    }
+
+    println!();
+    println!("{ANSI_BOLD}Summary{ANSI_RESET}");
+    println!("------");
+    println!("| Input | Part 1 | Part 2 |");
+    println!("| :--- | :---: | :---: |");
+
+    for (name, [part_1, part_2]) in &rows {
+        println!("| {name} | {part_1} | {part_2} |");
+    }
+
+    println!();
+    if mismatches > 0 {
+        eprintln!(
+            "{ANSI_BOLD}✗ {mismatches} answer(s) do not match the stored answers.{ANSI_RESET}"
+        );
+        process::exit(1);
+    }
+
+    println!("🎄 All known answers match.");
+}
+
+/// Read the answer registry, exiting if it can't be read.
+pub(crate) fn read_answers() -> Answers {
+    Answers::read_from_file().unwrap_or_else(|e| {
+        eprintln!("Failed to read the answer registry: {e}");
+        process::exit(1);
+    })
+}
+
+/// Run a solution part. The behavior differs depending on whether we are benching:
+///  1. by default, the function is executed once.
+///  2. with `--time`, the function is benched (approx. `bench_time` of execution time or 10 samples, whatever take longer.)
+///
+/// Allocations are only counted for the first run.
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
+    options: &RunOptions,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Duration, u128, Option<BenchStats>, Option<AllocStats>) {
     let timer = Instant::now();
-    let result = {
+    let (result, allocs) = alloc::measure(|| {
         let input = input.clone();
 
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
         func(input)
-    };
+    });
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
-    } else {
-        (base_time, 1)
-    };
+    if options.is_timed {
+        if let Some(stats) = bench(func, input, &base_time, options) {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let median = Duration::from_nanos(stats.median.round() as u64);
+            return (result, median, stats.samples, Some(stats), allocs);
+        }
+    }
 
-    (result, run.0, run.1)
+    (result, base_time, 1, None, allocs)
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+/// Human-readable output goes to stderr in machine-readable mode, to stdout otherwise.
+/// In machine-readable mode, stdout is reserved for records.
+fn human_output(options: &RunOptions) -> Box<dyn Write> {
+    if options.is_json {
+        Box::new(stderr())
+    } else {
+        Box::new(stdout())
+    }
+}
+
+/// Bench a solution part. A tenth of the iterations are run as warm-up and discarded,
+/// outliers are rejected before the statistics are computed.
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    options: &RunOptions,
+) -> Option<BenchStats> {
+    let mut out = human_output(options);
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    let _ = write!(out, " > {ANSI_ITALIC}benching{ANSI_RESET}");
+    let _ = out.flush();
 
     let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+        (options.bench_time.as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    let warmup_iterations = bench_iterations / 10;
+
+    for _ in 0..warmup_iterations {
+        black_box(func(black_box(input.clone())));
+    }
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -71,99 +514,219 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    BenchStats::from_samples(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+fn format_duration(duration: &Duration, samples: u128, stats: Option<&BenchStats>) -> String {
+    match stats {
+        Some(stats) => format!(" ({} @ {samples} samples)", stats.format_median()),
+        None if samples == 1 => format!(" ({duration:.1?})"),
+        None => format!(" ({duration:.1?} @ {samples} samples)"),
+    }
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+fn print_stats(stats: &BenchStats, options: &RunOptions) {
+    let _ = writeln!(
+        human_output(options),
+        "        {ANSI_ITALIC}min {}, p95 {}, max {}, {} outlier(s) rejected{ANSI_RESET}",
+        format_nanos(stats.min),
+        format_nanos(stats.p95),
+        format_nanos(stats.max),
+        stats.outliers
+    );
+}
+
+fn print_allocs(allocs: &AllocStats, options: &RunOptions) {
+    let _ = writeln!(
+        human_output(options),
+        "        {ANSI_ITALIC}{allocs}{ANSI_RESET}"
+    );
+}
+
+fn format_verification(verification: &Verification) -> String {
+    match verification {
+        Verification::Unknown => format!(" {ANSI_ITALIC}{verification}{ANSI_RESET}"),
+        _ => format!(" {verification}"),
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+/// A changed answer usually means a refactor broke something, so make sure it does not scroll past.
+fn print_mismatch<T: Display>(result: Option<&T>, expected: &str, part: &str) {
+    let actual = result.map_or_else(|| "✖".into(), ToString::to_string);
+    eprintln!(
+        "{ANSI_BOLD}✗ {part} does not match the stored answer! expected: {expected}, got: {actual}{ANSI_RESET}"
+    );
+}
+
+fn print_result<T: Display>(
+    result: &Option<T>,
+    part: &str,
+    duration_str: &str,
+    options: &RunOptions,
+) {
     let is_intermediate_result = duration_str.is_empty();
+    let mut out = human_output(options);
 
-    match result {
+    let _ = match result {
         Some(result) => {
             if result.to_string().contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    write!(out, "{str}")
                 } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
+                    write!(out, "\r{str}\n{result}\n")
                 }
             } else {
                 let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    write!(out, "{str}")
                 } else {
-                    print!("\r");
-                    println!("{str}");
+                    writeln!(out, "\r{str}")
                 }
             }
         }
         None => {
             if is_intermediate_result {
-                print!("{part}: ✖");
+                write!(out, "{part}: ✖")
             } else {
-                print!("\r");
-                println!("{part}: ✖             ");
+                writeln!(out, "\r{part}: ✖             ")
             }
         }
-    }
+    };
+
+    let _ = out.flush();
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+/// Explain that the puzzle input is the empty placeholder created by `cargo scaffold`.
+pub fn report_empty_input(day: Day) {
+    let path = data_dir_for(Some(day))
+        .join("inputs")
+        .join(format!("{day}.txt"));
+    eprintln!(
+        "Input \"{}\" is missing or empty. Run `cargo download {day}` to download your puzzle input.",
+        path.display()
+    );
+}
 
-    if !args.contains(&"--submit".into()) {
-        return None;
+/// Try to submit one part of the solution if:
+///  1. it was requested with `--submit`.
+///  2. it was computed from the puzzle input.
+///  3. earlier submissions do not rule it out, see [`Submissions::check`].
+///
+/// Submits with aoc-cli if it is installed, with the built-in client otherwise.
+fn submit_result<T: Display>(result: T, day: Day, part: u8, options: &RunOptions) {
+    if options.submit != Some(part) {
+        return;
     }
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    if !options.is_puzzle_input() {
+        let source = options
+            .example_file(day)
+            .or_else(|| options.input.clone())
+            .unwrap_or_default();
+        eprintln!(
+            "Not submitting: the result was computed from `{source}` instead of the puzzle input."
+        );
+        return;
+    }
+
+    let answer = result.to_string();
+    let mut submissions = match Submissions::read_from_file() {
+        Ok(submissions) => submissions,
+        Err(e) => {
+            eprintln!("Not submitting `{answer}`: {e}");
+            return;
+        }
+    };
+
+    if let Err(refusal) = submissions.check(day, part, &answer, submissions::now()) {
+        eprintln!("Not submitting `{answer}`: {refusal}");
+        return;
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    let is_native = aoc_client::is_preferred();
+    let timestamp = submissions::now();
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    let response = if is_native {
+        println!("Submitting result...");
+        aoc_client::submit(day, part, &answer).map_err(|e| e.to_string())
+    } else {
+        if aoc_cli::check().is_err() {
+            eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+            process::exit(1);
+        }
+
+        println!("Submitting result via aoc-cli...");
+        aoc_cli::submit(day, part, &answer).map_err(|e| e.to_string())
+    };
+
+    let response = match response {
+        Ok(response) => response,
+        Err(e) => {
+            eprintln!("Failed to submit the result: {e}");
+            return;
+        }
     };
 
-    if part_submit != part {
-        return None;
+    let submission = Submission::from_response(day, part, &answer, &response, timestamp);
+    print_verdict(&submission);
+
+    if submission.verdict == Verdict::Correct {
+        accept_answer(day, part, &answer, is_native);
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    submissions.data.push(submission);
+    if let Err(e) = submissions.store_file() {
+        eprintln!("Failed to store submission: {e}");
+    }
+}
+
+fn print_verdict(submission: &Submission) {
+    let wait = submission
+        .wait
+        .map(|wait| format!(" Wait {} before submitting again.", format_wait(wait)))
+        .unwrap_or_default();
+
+    println!(
+        "{ANSI_BOLD}Verdict{ANSI_RESET}: `{}` is {}.{wait}",
+        submission.answer, submission.verdict
+    );
+}
+
+/// Store an accepted answer in the answer registry and download the puzzle again, so that it includes part two.
+fn accept_answer(day: Day, part: u8, answer: &str, is_native: bool) {
+    // storing over a registry that can't be read would drop its answers.
+    match Answers::read_from_file() {
+        Ok(mut answers) => {
+            answers.set(day, part, answer.to_string());
+
+            match answers.store_file() {
+                Ok(()) => println!("⭐ Stored `{answer}` as the answer to part {part}."),
+                Err(e) => eprintln!("Failed to store the answer: {e}"),
+            }
+        }
+        Err(e) => eprintln!("Warning: not storing `{answer}` as the answer to part {part}: {e}"),
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    let downloaded = if is_native {
+        aoc_client::download_puzzle(day)
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    } else {
+        aoc_cli::download_puzzle(day)
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    };
+
+    match downloaded {
+        Ok(()) if part == 1 => println!(
+            "🎄 Downloaded part two to \"{}\".",
+            aoc_cli::get_puzzle_path(day)
+        ),
+        Ok(()) => println!(
+            "🎄 Updated the puzzle description \"{}\".",
+            aoc_cli::get_puzzle_path(day)
+        ),
+        Err(e) => eprintln!("Failed to download the puzzle description: {e}"),
+    }
 }
@@ -0,0 +1,198 @@
+/// Summary statistics of benchmark samples.
+use std::{collections::HashMap, time::Duration};
+use tinyjson::JsonValue;
+
+/// Tukey's fences: samples further than this many interquartile ranges outside the quartiles are outliers.
+const OUTLIER_FENCE: f64 = 1.5;
+
+/// Represents the statistics of a benchmarked solution part, with outliers removed.
+/// All durations are stored in nanoseconds.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchStats {
+    pub median: f64,
+    pub mean: f64,
+    pub min: f64,
+    pub max: f64,
+    pub std_dev: f64,
+    pub p95: f64,
+    /// Number of samples the statistics are based on.
+    pub samples: u128,
+    /// Number of samples that were rejected as outliers.
+    pub outliers: u128,
+}
+
+impl BenchStats {
+    /// Compute statistics over a set of samples. Returns `None` if there are no samples.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        sorted.sort_unstable_by(f64::total_cmp);
+
+        let q1 = percentile(&sorted, 25.0)?;
+        let q3 = percentile(&sorted, 75.0)?;
+        let iqr = q3 - q1;
+        let (low, high) = (q1 - OUTLIER_FENCE * iqr, q3 + OUTLIER_FENCE * iqr);
+
+        let kept: Vec<f64> = sorted
+            .iter()
+            .copied()
+            .filter(|x| *x >= low && *x <= high)
+            .collect();
+
+        let n = kept.len() as f64;
+        let mean = kept.iter().sum::<f64>() / n;
+        let variance = if kept.len() > 1 {
+            kept.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        } else {
+            0.0
+        };
+
+        Some(BenchStats {
+            median: percentile(&kept, 50.0)?,
+            mean,
+            min: *kept.first()?,
+            max: *kept.last()?,
+            std_dev: variance.sqrt(),
+            p95: percentile(&kept, 95.0)?,
+            samples: kept.len() as u128,
+            outliers: (sorted.len() - kept.len()) as u128,
+        })
+    }
+
+    /// Formats the median with its spread, e.g. `1.2ms ± 40.0µs`.
+    pub fn format_median(&self) -> String {
+        format!(
+            "{} ± {}",
+            format_nanos(self.median),
+            format_nanos(self.std_dev)
+        )
+    }
+}
+
+/// Linearly interpolated percentile of sorted values.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn percentile(sorted: &[f64], p: f64) -> Option<f64> {
+    let last = sorted.len().checked_sub(1)?;
+    let rank = p / 100.0 * last as f64;
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    Some(sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - rank.floor()))
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn format_nanos(nanos: f64) -> String {
+    format!("{:.1?}", Duration::from_nanos(nanos.round() as u64))
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&BenchStats> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("median".into(), JsonValue::Number(value.median));
+        map.insert("mean".into(), JsonValue::Number(value.mean));
+        map.insert("min".into(), JsonValue::Number(value.min));
+        map.insert("max".into(), JsonValue::Number(value.max));
+        map.insert("std_dev".into(), JsonValue::Number(value.std_dev));
+        map.insert("p95".into(), JsonValue::Number(value.p95));
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        Ok(BenchStats {
+            median: number("median")?,
+            mean: number("mean")?,
+            min: number("min")?,
+            max: number("max")?,
+            std_dev: number("std_dev")?,
+            p95: number("p95")?,
+            samples: number("samples")? as u128,
+            outliers: number("outliers")? as u128,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+    use tinyjson::JsonValue;
+
+    use super::BenchStats;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|x| Duration::from_nanos(*x)).collect()
+    }
+
+    #[test]
+    fn computes_statistics() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 20, 30, 40, 50])).unwrap();
+        assert_eq!(stats.median, 30.0);
+        assert_eq!(stats.mean, 30.0);
+        assert_eq!(stats.min, 10.0);
+        assert_eq!(stats.max, 50.0);
+        assert_eq!(stats.p95, 48.0);
+        assert_eq!(stats.std_dev, 250_f64.sqrt());
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats =
+            BenchStats::from_samples(&nanos(&[100, 101, 99, 100, 102, 98, 100, 5000])).unwrap();
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.samples, 7);
+        assert_eq!(stats.max, 102.0);
+        assert_eq!(stats.median, 100.0);
+    }
+
+    #[test]
+    fn handles_single_samples() {
+        let stats = BenchStats::from_samples(&nanos(&[42])).unwrap();
+        assert_eq!(stats.median, 42.0);
+        assert_eq!(stats.std_dev, 0.0);
+    }
+
+    #[test]
+    fn handles_empty_samples() {
+        assert_eq!(BenchStats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let stats = BenchStats::from_samples(&nanos(&[1, 2, 3])).unwrap();
+        let parsed = BenchStats::try_from(&JsonValue::from(&stats)).unwrap();
+        assert_eq!(parsed, stats);
+    }
+
+    #[test]
+    fn formats_median() {
+        let stats = BenchStats::from_samples(&nanos(&[1000, 1000, 1000])).unwrap();
+        assert_eq!(stats.format_median(), "1.0µs ± 0.0ns");
+    }
+}
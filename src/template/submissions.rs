@@ -0,0 +1,584 @@
+/// Log of submitted answers and the verdicts of the server.
+/// Used to refuse submissions that are known to be wrong and to respect the cooldown after a wrong answer.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::Error,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{data_dir, Day};
+
+/// Stored in the data directory of the current year.
+static SUBMISSIONS_FILE_NAME: &str = "submissions.json";
+
+/// The server does not always state how long to wait after a wrong answer.
+const DEFAULT_WAIT_SECS: u64 = 60;
+
+/// How the server responded to a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// The answer was submitted during the cooldown of a previous one and was not checked.
+    RateLimited,
+    /// The response could not be classified, e.g. because the part was already solved.
+    Unknown,
+}
+
+impl Verdict {
+    /// Classify the response of the server to a submission, e.g. `That's not the right answer; your answer is too high.`
+    pub fn from_response(response: &str) -> Self {
+        if response.contains("That's the right answer") {
+            Verdict::Correct
+        } else if response.contains("You gave an answer too recently") {
+            Verdict::RateLimited
+        } else if response.contains("That's not the right answer") {
+            if response.contains("too high") {
+                Verdict::TooHigh
+            } else if response.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    fn is_wrong(self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+
+    fn key(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::RateLimited => "rate_limited",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::RateLimited => write!(f, "rate-limited"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            Verdict::Correct,
+            Verdict::Wrong,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::RateLimited,
+            Verdict::Unknown,
+        ]
+        .into_iter()
+        .find(|verdict| verdict.key() == s)
+        .ok_or_else(|| format!("unknown verdict `{s}`."))
+    }
+}
+
+/// Seconds to wait before the next submission, e.g. `Please wait one minute` or `You have 1m 5s left to wait`.
+pub fn parse_wait(response: &str) -> Option<u64> {
+    if let Some(start) = response.find("You have ") {
+        let rest = &response[start + "You have ".len()..];
+        let end = rest.find(" left to wait")?;
+
+        return rest[..end]
+            .split_whitespace()
+            .map(|part| {
+                let (value, factor) = match part.strip_suffix('m') {
+                    Some(minutes) => (minutes, 60),
+                    None => (part.strip_suffix('s')?, 1),
+                };
+                Some(value.parse::<u64>().ok()? * factor)
+            })
+            .sum();
+    }
+
+    let start = response.find("lease wait ")?;
+    let mut words = response[start + "lease wait ".len()..].split_whitespace();
+    let value = match words.next()? {
+        "one" | "a" => 1,
+        value => value.parse().ok()?,
+    };
+
+    match words.next()? {
+        unit if unit.starts_with("minute") => Some(value * 60),
+        unit if unit.starts_with("second") => Some(value),
+        _ => None,
+    }
+}
+
+/// Format seconds like `1m 05s`.
+pub fn format_wait(secs: u64) -> String {
+    if secs >= 60 {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{secs}s")
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// A submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    /// Unix timestamp in seconds.
+    pub timestamp: u64,
+    pub verdict: Verdict,
+    /// Seconds to wait before the next submission of this part, if the server asked for it.
+    pub wait: Option<u64>,
+}
+
+impl Submission {
+    /// Record the response of the server to an answer submitted at `timestamp`.
+    pub fn from_response(day: Day, part: u8, answer: &str, response: &str, timestamp: u64) -> Self {
+        let verdict = Verdict::from_response(response);
+
+        let wait = match parse_wait(response) {
+            Some(wait) => Some(wait),
+            None if verdict.is_wrong() => Some(DEFAULT_WAIT_SECS),
+            None => None,
+        };
+
+        Submission {
+            day,
+            part,
+            answer: answer.to_string(),
+            timestamp,
+            verdict,
+            wait,
+        }
+    }
+}
+
+/// Why an answer is not submitted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    /// The part was already solved.
+    Solved { answer: String },
+    /// The same answer was submitted before and was wrong.
+    KnownWrong { verdict: Verdict },
+    /// An answer at least as high as this was too high.
+    TooHigh { bound: String },
+    /// An answer at least as low as this was too low.
+    TooLow { bound: String },
+    /// The cooldown of the last submission has not passed yet.
+    Cooldown { remaining: u64 },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::Solved { answer } => {
+                write!(f, "this part was already solved with `{answer}`.")
+            }
+            Refusal::KnownWrong { verdict } => {
+                write!(f, "this answer was submitted before and was {verdict}.")
+            }
+            Refusal::TooHigh { bound } => {
+                write!(f, "`{bound}` was too high, the answer must be lower.")
+            }
+            Refusal::TooLow { bound } => {
+                write!(f, "`{bound}` was too low, the answer must be higher.")
+            }
+            Refusal::Cooldown { remaining } => {
+                write!(
+                    f,
+                    "wait {} before submitting again.",
+                    format_wait(*remaining)
+                )
+            }
+        }
+    }
+}
+
+/// Every submission of the current year, stored in `submissions.json`.
+#[derive(Clone, Debug, Default)]
+pub struct Submissions {
+    pub data: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Dehydrate submissions to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(data_dir().join(SUBMISSIONS_FILE_NAME))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate submissions from a JSON file. If not present, returns no submissions.
+    /// A file that can't be read is an error, since submitting without its guards could repeat a wrong answer.
+    pub fn read_from_file() -> Result<Self, String> {
+        let path = data_dir().join(SUBMISSIONS_FILE_NAME);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(Submissions::try_from)
+            .map_err(|e| format!("could not read `{}`: {e}", path.display()))
+    }
+
+    /// Check whether an answer may be submitted at `now`, given the earlier submissions of the part.
+    pub fn check(&self, day: Day, part: u8, answer: &str, now: u64) -> Result<(), Refusal> {
+        let previous: Vec<&Submission> = self
+            .data
+            .iter()
+            .filter(|s| s.day == day && s.part == part)
+            .collect();
+
+        if let Some(solved) = previous.iter().find(|s| s.verdict == Verdict::Correct) {
+            return Err(Refusal::Solved {
+                answer: solved.answer.clone(),
+            });
+        }
+
+        if let Some(known) = previous
+            .iter()
+            .find(|s| s.answer == answer && s.verdict.is_wrong())
+        {
+            return Err(Refusal::KnownWrong {
+                verdict: known.verdict,
+            });
+        }
+
+        // bounds only apply to numeric answers.
+        if let Ok(value) = answer.parse::<i128>() {
+            if let Some(bound) = bounds(&previous, Verdict::TooHigh)
+                .min()
+                .filter(|bound| value >= bound.0)
+            {
+                return Err(Refusal::TooHigh { bound: bound.1 });
+            }
+
+            if let Some(bound) = bounds(&previous, Verdict::TooLow)
+                .max()
+                .filter(|bound| value <= bound.0)
+            {
+                return Err(Refusal::TooLow { bound: bound.1 });
+            }
+        }
+
+        let remaining = previous
+            .iter()
+            .filter_map(|s| (s.timestamp + s.wait?).checked_sub(now))
+            .max()
+            .filter(|remaining| *remaining > 0);
+
+        match remaining {
+            Some(remaining) => Err(Refusal::Cooldown { remaining }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Numeric answers of the submissions with a verdict, e.g. every answer that was too high.
+fn bounds<'a>(
+    submissions: &'a [&Submission],
+    verdict: Verdict,
+) -> impl Iterator<Item = (i128, String)> + 'a {
+    submissions
+        .iter()
+        .filter(move |s| s.verdict == verdict)
+        .filter_map(|s| Some((s.answer.parse().ok()?, s.answer.clone())))
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Submissions> for JsonValue {
+    fn from(value: Submissions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Submissions {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Submissions {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(value.verdict.key().into()),
+        );
+        map.insert(
+            "wait".into(),
+            value
+                .wait
+                .map_or(JsonValue::Null, |wait| JsonValue::Number(wait as f64)),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|part| *part as u8)
+            .ok_or("Expected submission.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|timestamp| *timestamp as u64)
+            .ok_or("Expected submission.timestamp to be a number.")?;
+
+        let verdict = json
+            .get("verdict")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.verdict to be a string.")?
+            .parse()?;
+
+        let wait = match json.get("wait") {
+            Some(v) if !v.is_null() => Some(
+                *v.get::<f64>()
+                    .ok_or("Expected submission.wait to be null or a number.")?
+                    as u64,
+            ),
+            _ => None,
+        };
+
+        Ok(Submission {
+            day,
+            part,
+            answer: answer.clone(),
+            timestamp,
+            verdict,
+            wait,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_wait, Refusal, Submission, Submissions, Verdict};
+    use crate::day;
+
+    fn submission(answer: &str, verdict: Verdict, timestamp: u64) -> Submission {
+        Submission {
+            day: day!(1),
+            part: 1,
+            answer: answer.into(),
+            timestamp,
+            verdict,
+            wait: verdict.is_wrong().then_some(60),
+        }
+    }
+
+    #[test]
+    fn classifies_responses() {
+        assert_eq!(
+            Verdict::from_response("That's the right answer! You are one gold star closer."),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer; your answer is too high."),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer. If you're stuck, ..."),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            Verdict::from_response("You gave an answer too recently; you have to wait."),
+            Verdict::RateLimited
+        );
+        assert_eq!(
+            Verdict::from_response("You don't seem to be solving the right level."),
+            Verdict::Unknown
+        );
+    }
+
+    #[test]
+    fn parses_waits() {
+        assert_eq!(
+            parse_wait("Please wait one minute before trying again."),
+            Some(60)
+        );
+        assert_eq!(
+            parse_wait("please wait 5 minutes before trying again."),
+            Some(300)
+        );
+        assert_eq!(parse_wait("You have 1m 5s left to wait."), Some(65));
+        assert_eq!(parse_wait("You have 31s left to wait."), Some(31));
+        assert_eq!(parse_wait("That's the right answer!"), None);
+    }
+
+    #[test]
+    fn defaults_the_wait_of_wrong_answers() {
+        let wrong = Submission::from_response(day!(1), 1, "1", "That's not the right answer.", 0);
+        assert_eq!(wrong.wait, Some(60));
+
+        let correct = Submission::from_response(day!(1), 1, "2", "That's the right answer!", 0);
+        assert_eq!(correct.wait, None);
+    }
+
+    #[test]
+    fn refuses_known_answers() {
+        let submissions = Submissions {
+            data: vec![
+                submission("100", Verdict::TooHigh, 0),
+                submission("10", Verdict::TooLow, 0),
+                submission("abc", Verdict::Wrong, 0),
+            ],
+        };
+
+        assert_eq!(
+            submissions.check(day!(1), 1, "abc", 1000),
+            Err(Refusal::KnownWrong {
+                verdict: Verdict::Wrong
+            })
+        );
+        assert_eq!(
+            submissions.check(day!(1), 1, "120", 1000),
+            Err(Refusal::TooHigh {
+                bound: "100".into()
+            })
+        );
+        assert_eq!(
+            submissions.check(day!(1), 1, "10", 1000),
+            Err(Refusal::KnownWrong {
+                verdict: Verdict::TooLow
+            })
+        );
+        assert_eq!(
+            submissions.check(day!(1), 1, "5", 1000),
+            Err(Refusal::TooLow { bound: "10".into() })
+        );
+        assert_eq!(submissions.check(day!(1), 1, "50", 1000), Ok(()));
+        assert_eq!(submissions.check(day!(1), 2, "120", 1000), Ok(()));
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        let submissions = Submissions {
+            data: vec![submission("42", Verdict::Correct, 0)],
+        };
+        assert_eq!(
+            submissions.check(day!(1), 1, "42", 1000),
+            Err(Refusal::Solved {
+                answer: "42".into()
+            })
+        );
+    }
+
+    #[test]
+    fn enforces_cooldowns() {
+        let submissions = Submissions {
+            data: vec![submission("100", Verdict::Wrong, 1000)],
+        };
+        assert_eq!(
+            submissions.check(day!(1), 1, "50", 1045),
+            Err(Refusal::Cooldown { remaining: 15 })
+        );
+        assert_eq!(submissions.check(day!(1), 1, "50", 1060), Ok(()));
+    }
+
+    #[test]
+    fn roundtrips_submissions() {
+        let submissions = Submissions {
+            data: vec![
+                submission("100", Verdict::TooHigh, 1700000000),
+                submission("42", Verdict::Correct, 1700000100),
+            ],
+        };
+        let json = tinyjson::JsonValue::from(submissions.clone())
+            .stringify()
+            .unwrap();
+        let parsed = Submissions::try_from(json).unwrap();
+        assert_eq!(parsed.data, submissions.data);
+    }
+}
@@ -1,9 +1,14 @@
 use std::{collections::HashMap, fs, io::Error, str::FromStr};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::alloc::AllocStats;
+use crate::template::history::{HistoryEntry, RunMetadata};
+use crate::template::records::{PartRecord, PartStatus};
+use crate::template::stats::BenchStats;
+use crate::template::{data_dir, Day};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+/// Stored in the data directory of the current year.
+static TIMINGS_FILE_NAME: &str = "timings.json";
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
@@ -11,33 +16,122 @@ pub struct Timing {
     pub day: Day,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    pub part_1_stats: Option<BenchStats>,
+    pub part_2_stats: Option<BenchStats>,
+    /// Outcome of each part, if it ran. Parts that timed out, ran out of memory or crashed have no duration.
+    pub part_1_status: Option<PartStatus>,
+    pub part_2_status: Option<PartStatus>,
+    /// Allocations of each part, if the solution counted them.
+    pub part_1_allocs: Option<AllocStats>,
+    pub part_2_allocs: Option<AllocStats>,
     pub total_nanos: f64,
 }
 
+impl Timing {
+    /// Collect the timings of a day from the records emitted by its solution.
+    pub fn from_records(day: Day, records: &[PartRecord]) -> Self {
+        let mut timing = Timing {
+            day,
+            part_1: None,
+            part_2: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            part_1_status: None,
+            part_2_status: None,
+            part_1_allocs: None,
+            part_2_allocs: None,
+            total_nanos: 0_f64,
+        };
+
+        for record in records {
+            match record.part {
+                1 => timing.part_1_status = Some(record.status),
+                2 => timing.part_2_status = Some(record.status),
+                _ => continue,
+            }
+        }
+
+        for record in records.iter().filter(|r| r.status == PartStatus::Solved) {
+            match record.part {
+                1 => {
+                    timing.part_1 = Some(record.duration.clone());
+                    timing.part_1_stats.clone_from(&record.stats);
+                    timing.part_1_allocs.clone_from(&record.allocs);
+                }
+                2 => {
+                    timing.part_2 = Some(record.duration.clone());
+                    timing.part_2_stats.clone_from(&record.stats);
+                    timing.part_2_allocs.clone_from(&record.allocs);
+                }
+                _ => continue,
+            }
+
+            timing.total_nanos += record.nanos;
+        }
+
+        timing
+    }
+}
+
 /// Represents benchmark times for a set of days.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
 pub struct Timings {
+    /// Latest timing of each day.
     pub data: Vec<Timing>,
+    /// Every stored timing, oldest first. Entries are only ever appended.
+    pub history: Vec<HistoryEntry>,
 }
 
 impl Timings {
     /// Dehydrate timings to a JSON file.
     pub fn store_file(&self) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(data_dir().join(TIMINGS_FILE_NAME))?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
-            .map_err(|x| x.to_string())
+    /// A file that can't be read is an error, so that storing new timings never drops the history.
+    pub fn read_from_file() -> Result<Self, String> {
+        let path = data_dir().join(TIMINGS_FILE_NAME);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
             .and_then(Timings::try_from)
-            .unwrap_or_default()
+            .map_err(|e| format!("could not read `{}`: {e}", path.display()))
+    }
+
+    /// Append the current timings to the history, tagged with `metadata`.
+    /// Days without a solved part are not recorded.
+    #[must_use]
+    pub fn with_history(mut self, metadata: &RunMetadata) -> Self {
+        let entries = self
+            .data
+            .iter()
+            .filter(|t| t.part_1.is_some() || t.part_2.is_some())
+            .map(|timing| HistoryEntry {
+                metadata: metadata.clone(),
+                timing: timing.clone(),
+            });
+
+        self.history.extend(entries);
+        self
+    }
+
+    /// History of a single day, oldest first.
+    pub fn history_for(&self, day: Day) -> Vec<&HistoryEntry> {
+        self.history
+            .iter()
+            .filter(|e| e.timing.day == day)
+            .collect()
     }
 
     /// Merge two sets of timings, overwriting `self` with `other` if present.
+    /// The history of `other` is appended to the history of `self`.
     pub fn merge(&self, new: &Self) -> Self {
         let mut data: Vec<Timing> = vec![];
 
@@ -51,8 +145,16 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
-        Timings { data }
+        data.sort_unstable_by_key(|a| a.day);
+
+        let history = self
+            .history
+            .iter()
+            .chain(new.history.iter())
+            .cloned()
+            .collect();
+
+        Timings { data, history }
     }
 
     /// Sum up total duration of timings as millis.
@@ -78,6 +180,11 @@ impl From<Timings> for JsonValue {
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
         );
 
+        map.insert(
+            "history".into(),
+            JsonValue::Array(value.history.iter().map(JsonValue::from).collect()),
+        );
+
         JsonValue::Object(map)
     }
 }
@@ -88,19 +195,34 @@ impl TryFrom<String> for Timings {
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
 
-        let json_data = json
+        let json = json
             .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
+            .ok_or("expected JSON document to be an object.")?;
+
+        let json_data = json
             .get("data")
             .ok_or("expected JSON document to have key `data`.")?
             .get::<Vec<JsonValue>>()
             .ok_or("expected `json.data` to be an array.")?;
 
+        // NOTE: timings stored before history was recorded do not have this key.
+        let json_history = match json.get("history") {
+            Some(v) => v
+                .get::<Vec<JsonValue>>()
+                .ok_or("expected `json.history` to be an array.")?
+                .as_slice(),
+            None => &[],
+        };
+
         Ok(Timings {
             data: json_data
                 .iter()
                 .map(Timing::try_from)
                 .collect::<Result<_, _>>()?,
+            history: json_history
+                .iter()
+                .map(HistoryEntry::try_from)
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -133,6 +255,36 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        map.insert(
+            "part_1_stats".into(),
+            value
+                .part_1_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "part_2_stats".into(),
+            value
+                .part_2_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        let status = |v: Option<PartStatus>| {
+            v.map_or(JsonValue::Null, |status| {
+                JsonValue::String(status.to_string())
+            })
+        };
+
+        map.insert("part_1_status".into(), status(value.part_1_status));
+        map.insert("part_2_status".into(), status(value.part_2_status));
+
+        let allocs = |v: &Option<AllocStats>| v.as_ref().map_or(JsonValue::Null, JsonValue::from);
+
+        map.insert("part_1_allocs".into(), allocs(&value.part_1_allocs));
+        map.insert("part_2_allocs".into(), allocs(&value.part_2_allocs));
+
         JsonValue::Object(map)
     }
 }
@@ -161,6 +313,28 @@ impl TryFrom<&JsonValue> for Timing {
             .map(|v| if v.is_null() { None } else { v.get::<String>() })
             .ok_or("Expected timing.part_2 to be null or string.")?;
 
+        // NOTE: timings stored before statistics were recorded do not have these keys.
+        let stats = |key: &str| match json.get(key) {
+            Some(v) if !v.is_null() => BenchStats::try_from(v).map(Some),
+            _ => Ok(None),
+        };
+
+        // NOTE: timings stored before statuses were recorded do not have these keys.
+        let status = |key: &str| match json.get(key) {
+            Some(v) if !v.is_null() => v
+                .get::<String>()
+                .ok_or(format!("Expected timing.{key} to be null or string."))?
+                .parse()
+                .map(Some),
+            _ => Ok(None),
+        };
+
+        // NOTE: allocations are only recorded with the `alloc-count` feature.
+        let allocs = |key: &str| match json.get(key) {
+            Some(v) if !v.is_null() => AllocStats::try_from(v).map(Some),
+            _ => Ok(None),
+        };
+
         let total_nanos = json
             .get("total_nanos")
             .and_then(|v| v.get::<f64>().copied())
@@ -170,6 +344,12 @@ impl TryFrom<&JsonValue> for Timing {
             day,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
+            part_1_stats: stats("part_1_stats")?,
+            part_2_stats: stats("part_2_stats")?,
+            part_1_status: status("part_1_status")?,
+            part_2_status: status("part_2_status")?,
+            part_1_allocs: allocs("part_1_allocs")?,
+            part_2_allocs: allocs("part_2_allocs")?,
             total_nanos,
         })
     }
@@ -190,21 +370,40 @@ mod tests {
                     day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    part_1_allocs: None,
+                    part_2_allocs: None,
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    part_1_allocs: None,
+                    part_2_allocs: None,
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    part_1_allocs: None,
+                    part_2_allocs: None,
                     total_nanos: 4e+10,
                 },
             ],
+            history: vec![],
         }
     }
 
@@ -223,6 +422,36 @@ mod tests {
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_timings_with_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_stats": { "median": 1000000, "mean": 1000000, "min": 900000, "max": 1100000, "std_dev": 50000, "p95": 1080000, "samples": 100, "outliers": 2 }, "part_2_stats": null }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let stats = timing.part_1_stats.as_ref().unwrap();
+            assert_eq!(stats.median, 1_000_000_f64);
+            assert_eq!(stats.outliers, 2);
+            assert_eq!(timing.part_2_stats, None);
+        }
+
+        #[test]
+        fn handles_json_history() {
+            let json = r#"{ "data": [], "history": [{ "timestamp": 1733029200, "commit": "abc1234", "rustc": null, "machine": "ferris", "timing": { "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.history.len(), 1);
+            let entry = timings.history.first().unwrap();
+            assert_eq!(entry.metadata.timestamp, 1_733_029_200);
+            assert_eq!(entry.metadata.commit, Some("abc1234".into()));
+            assert_eq!(entry.metadata.rustc, None);
+            assert_eq!(entry.timing.day, day!(1));
+        }
+
+        #[test]
+        fn handles_timings_without_history() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.history.len(), 0);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -281,11 +510,18 @@ mod tests {
                     day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    part_1_allocs: None,
+                    part_2_allocs: None,
                     total_nanos: 3_000_000_000_f64,
                 }],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -295,11 +531,18 @@ mod tests {
                     day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    part_1_allocs: None,
+                    part_2_allocs: None,
                     total_nanos: 1_000_000_000_f64,
                 }],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -309,11 +552,85 @@ mod tests {
                     day: day!(1),
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    part_1_allocs: None,
+                    part_2_allocs: None,
                     total_nanos: 0.0,
                 }],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+    }
+
+    mod from_records {
+        use std::time::Duration;
+
+        use crate::{
+            day,
+            template::{
+                records::{PartRecord, PartStatus},
+                timings::Timing,
+            },
+        };
+
+        #[test]
+        fn collects_part_timings() {
+            let timing = Timing::from_records(
+                day!(1),
+                &[
+                    PartRecord::new(
+                        1,
+                        Some("0".into()),
+                        &Duration::from_nanos(74),
+                        100_000,
+                        None,
+                    ),
+                    PartRecord::new(
+                        2,
+                        Some("10".into()),
+                        &Duration::from_millis(74),
+                        99_999,
+                        None,
+                    ),
+                ],
+            );
+            assert_eq!(timing.total_nanos, 74_000_074_f64);
+            assert_eq!(timing.part_1.unwrap(), "74.0ns");
+            assert_eq!(timing.part_2.unwrap(), "74.0ms");
+        }
+
+        #[test]
+        fn handles_unsolved_parts() {
+            let timing = Timing::from_records(
+                day!(1),
+                &[
+                    PartRecord::new(1, None, &Duration::from_nanos(74), 1, None),
+                    PartRecord::new(2, Some("@ ( ) ms".into()), &Duration::from_secs(2), 5, None),
+                ],
+            );
+            assert_eq!(timing.total_nanos, 2_000_000_000_f64);
+            assert_eq!(timing.part_1.is_none(), true);
+            assert_eq!(timing.part_2.unwrap(), "2.0s");
+        }
+
+        #[test]
+        fn collects_part_statuses() {
+            let timing = Timing::from_records(
+                day!(1),
+                &[
+                    PartRecord::new(1, Some("0".into()), &Duration::from_nanos(74), 1, None),
+                    PartRecord::aborted(2, PartStatus::TimedOut, &Duration::from_secs(10)),
+                ],
+            );
+            assert_eq!(timing.total_nanos, 74_f64);
+            assert_eq!(timing.part_1_status, Some(PartStatus::Solved));
+            assert_eq!(timing.part_2_status, Some(PartStatus::TimedOut));
+            assert_eq!(timing.part_2.is_none(), true);
         }
     }
 
@@ -333,8 +650,15 @@ mod tests {
                     day: day!(3),
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    part_1_allocs: None,
+                    part_2_allocs: None,
                     total_nanos: 0_f64,
                 }],
+                history: vec![],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
@@ -353,8 +677,15 @@ mod tests {
                     day: day!(2),
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    part_1_allocs: None,
+                    part_2_allocs: None,
                     total_nanos: 0_f64,
                 }],
+                history: vec![],
             };
             let merged = timings.merge(&other);
 
@@ -381,4 +712,51 @@ mod tests {
             assert_eq!(merged.data.len(), 3);
         }
     }
+
+    mod history {
+        use crate::{day, template::history::RunMetadata, template::timings::Timings};
+
+        use super::get_mock_timings;
+
+        fn get_mock_metadata(timestamp: u64) -> RunMetadata {
+            RunMetadata {
+                timestamp,
+                commit: Some("abc1234".into()),
+                rustc: None,
+                machine: None,
+            }
+        }
+
+        #[test]
+        fn records_solved_days() {
+            let mut timings = get_mock_timings();
+            timings.data[2].part_1 = None;
+            let timings = timings.with_history(&get_mock_metadata(1));
+            assert_eq!(timings.history.len(), 2);
+            assert_eq!(timings.history[0].timing.day, day!(1));
+            assert_eq!(timings.history[1].timing.day, day!(2));
+        }
+
+        #[test]
+        fn appends_history_when_merging() {
+            let stored = get_mock_timings().with_history(&get_mock_metadata(1));
+            let new = get_mock_timings().with_history(&get_mock_metadata(2));
+            let merged = stored.merge(&new);
+
+            assert_eq!(merged.data.len(), 3);
+            assert_eq!(merged.history.len(), 6);
+            assert_eq!(merged.history[0].metadata.timestamp, 1);
+            assert_eq!(merged.history[5].metadata.timestamp, 2);
+        }
+
+        #[test]
+        fn filters_history_by_day() {
+            let stored = get_mock_timings().with_history(&get_mock_metadata(1));
+            let merged = stored.merge(&Timings::default().with_history(&get_mock_metadata(2)));
+            let history = merged.history_for(day!(2));
+            assert_eq!(history.len(), 1);
+            assert_eq!(history[0].timing.day, day!(2));
+            assert_eq!(merged.history_for(day!(3)).len(), 0);
+        }
+    }
 }
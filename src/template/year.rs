@@ -0,0 +1,232 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use crate::template::{AllDays, Day};
+
+/// The first event was held in 2015.
+const FIRST_YEAR: u16 = 2015;
+
+/// Starting with 2025, events have 12 days instead of 25.
+const FIRST_SHORT_YEAR: u16 = 2025;
+
+/// The last day of the longest events, used when no year is configured.
+pub(crate) const MAX_DAY: u8 = 25;
+
+static SELECTED_YEAR: OnceLock<Year> = OnceLock::new();
+
+/// The year of an Advent of Code event (i.e. 2015 or later).
+///
+/// The year configured with `AOC_YEAR` uses the flat layout: solutions in `src/bin/NN.rs`, data in `data/`.
+/// Other years live in per-year directories: solutions in `src/bin/YYYY/NN.rs`, data in `data/YYYY/`.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2023).unwrap();
+/// assert_eq!(year.to_string(), "2023");
+/// assert_eq!(year.last_day(), 25);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if an event was held in it,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// The year configured with the `AOC_YEAR` environment variable, see `.cargo/config.toml`.
+    pub fn configured() -> Option<Self> {
+        env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+
+    /// Select the year that commands and solutions in this process work on, e.g. with `--year`.
+    /// Only the first selection takes effect.
+    pub fn select(year: Self) {
+        let _ = SELECTED_YEAR.set(year);
+    }
+
+    /// The selected year, or the configured year if none was selected.
+    pub fn current() -> Option<Self> {
+        SELECTED_YEAR.get().copied().or_else(Self::configured)
+    }
+
+    /// The last day of the event.
+    pub const fn last_day(self) -> u8 {
+        if self.0 >= FIRST_SHORT_YEAR {
+            12
+        } else {
+            MAX_DAY
+        }
+    }
+
+    /// The last day of the current year's event, or the 25th if no year is configured.
+    pub fn current_last_day() -> u8 {
+        Self::current().map_or(MAX_DAY, Self::last_day)
+    }
+
+    // Not part of the public API, used by `day!` to check days against `AOC_YEAR` at compile time.
+    #[doc(hidden)]
+    pub const fn __last_day_of(year: Option<&str>) -> u8 {
+        let Some(year) = year else {
+            return MAX_DAY;
+        };
+
+        let bytes = year.as_bytes();
+        let mut value: u16 = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            if !bytes[i].is_ascii_digit() || i >= 4 {
+                return MAX_DAY;
+            }
+            value = value * 10 + (bytes[i] - b'0') as u16;
+            i += 1;
+        }
+
+        if value < FIRST_YEAR {
+            return MAX_DAY;
+        }
+        Self(value).last_day()
+    }
+
+    /// Whether the event has a puzzle on this day.
+    pub fn has_day(self, day: Day) -> bool {
+        day <= self.last_day()
+    }
+
+    /// Every day of the event.
+    pub fn days(self) -> AllDays {
+        AllDays::until(self.last_day())
+    }
+
+    /// Whether this year lives in per-year directories.
+    fn has_own_layout(self) -> bool {
+        Some(self) != Self::configured()
+    }
+}
+
+/// The year of a day, if it uses per-year directories.
+fn layout_year(day: Option<Day>) -> Option<Year> {
+    day.and_then(Day::year)
+        .or_else(Year::current)
+        .filter(|year| year.has_own_layout())
+}
+
+/// Data directory of the current year, e.g. `data` or `data/2023`.
+pub fn data_dir() -> PathBuf {
+    data_dir_for(None)
+}
+
+/// Data directory of the year a day belongs to. Days of solutions know their year, others use the current year.
+pub fn data_dir_for(day: Option<Day>) -> PathBuf {
+    match layout_year(day) {
+        Some(year) => PathBuf::from("data").join(year.to_string()),
+        None => PathBuf::from("data"),
+    }
+}
+
+/// Name of the solution bin of a day of the current year, e.g. `05` or `2023-05`.
+pub fn bin_name(day: Day) -> String {
+    match layout_year(None) {
+        Some(year) => format!("{year}-{day}"),
+        None => day.to_string(),
+    }
+}
+
+/// Path of the solution module of a day of the current year, e.g. `src/bin/05.rs` or `src/bin/2023/05.rs`.
+pub fn bin_path(day: Day) -> String {
+    match layout_year(None) {
+        Some(year) => format!("src/bin/{year}/{day}.rs"),
+        None => format!("src/bin/{day}.rs"),
+    }
+}
+
+/// Whether the current year uses the flat layout, i.e. is the configured year.
+pub fn is_configured_year() -> bool {
+    layout_year(None).is_none()
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expecting a year of {FIRST_YEAR} or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+    use crate::template::Day;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2023".parse::<Year>().unwrap(), Year::new(2023).unwrap());
+        assert!("2014".parse::<Year>().is_err());
+        assert!("twenty".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn knows_the_days_of_an_event() {
+        let year = Year::new(2024).unwrap();
+        assert_eq!(year.last_day(), 25);
+        assert!(year.has_day(Day::__new_unchecked(25)));
+        assert_eq!(year.days().count(), 25);
+
+        let year = Year::new(2025).unwrap();
+        assert_eq!(year.last_day(), 12);
+        assert!(year.has_day(Day::__new_unchecked(12)));
+        assert!(!year.has_day(Day::__new_unchecked(13)));
+        assert_eq!(year.days().last(), Some(Day::__new_unchecked(12)));
+    }
+
+    #[test]
+    fn reads_the_last_day_at_compile_time() {
+        assert_eq!(Year::__last_day_of(Some("2024")), 25);
+        assert_eq!(Year::__last_day_of(Some("2025")), 12);
+        assert_eq!(Year::__last_day_of(Some("")), 25);
+        assert_eq!(Year::__last_day_of(Some("20x5")), 25);
+        assert_eq!(Year::__last_day_of(None), 25);
+    }
+}